@@ -0,0 +1,402 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing of the command line arguments.
+
+use crate::config::ColorChoice;
+use crate::parse::{Endianness, QFormat};
+use crate::print::Precision;
+use crate::render::{Format, REPORT_FORMATS};
+use derive_more::Display;
+use std::error::Error;
+use std::path::PathBuf;
+
+/// What the user wants wambo to do.
+#[derive(Debug)]
+pub enum Command {
+    /// Print the help and exit.
+    Help,
+    /// Print the input history and exit. Contains the path of the config
+    /// file, if passed via `--config`, and whether `--no-history` was
+    /// passed, as both can disable the history.
+    History {
+        config: Option<PathBuf>,
+        no_history: bool,
+    },
+    /// Print the effective configuration and exit. Contains the path of the
+    /// config file, if passed via `--config`.
+    PrintConfig(Option<PathBuf>),
+    /// Interpret the input and show the result.
+    Run(CliArgs),
+}
+
+/// Validated command line arguments for [`Command::Run`].
+#[derive(Debug, Default)]
+pub struct CliArgs {
+    /// The value to interpret, as typed by the user.
+    pub input: String,
+    /// Additional fixed-point formats, passed via `--q`.
+    pub q_formats: Vec<QFormat>,
+    /// Byte order, passed via `--endian`. If `None`, the config decides.
+    pub endianness: Option<Endianness>,
+    /// Width in bits, passed via `--width`. If `None`, the config decides.
+    pub width: Option<u32>,
+    /// Print the source code literals instead of showing the TUI, passed
+    /// via `--literals`.
+    pub literals: bool,
+    /// Don't read or write the history file, passed via `--no-history`.
+    pub no_history: bool,
+    /// Path of the config file, passed via `--config`. If `None`, the
+    /// default location is used.
+    pub config: Option<PathBuf>,
+    /// Whether colors are used, passed via `--color`.
+    pub color: ColorChoice,
+    /// The output format, passed via `--format`.
+    pub format: Format,
+    /// The format of a report with one row per input, passed via
+    /// `--export`.
+    pub export: Option<Format>,
+    /// How floats and sizes are formatted, passed via `--precision`. If
+    /// `None`, the config decides.
+    pub precision: Option<Precision>,
+}
+
+/// Errors for invalid command line arguments.
+#[derive(Debug, Display)]
+pub enum CliError {
+    /// No input value was given.
+    #[display("Missing input value")]
+    MissingInput,
+    /// An option requires a value but none was given.
+    #[display("Option {_0} requires a value")]
+    MissingValue(String),
+    /// The value of an option is invalid.
+    #[display("Invalid value for {_0}: {_1}")]
+    InvalidValue(String, String),
+    /// The option is not known.
+    #[display("Unknown option: {_0}")]
+    UnknownOption(String),
+}
+
+impl Error for CliError {}
+
+/// Parses the command line arguments, without the program name.
+///
+/// Options start with `--` and take their value either as next argument or
+/// after a `=`, such as `--q 12.4` or `--q=12.4`. Everything else is the
+/// input. Negative inputs, such as `-7`, are therefore not mistaken for
+/// options. All arguments after `--` are input, even if they start with
+/// `--`. Multiple input arguments are joined with spaces, so that byte
+/// sequences such as `de ad be ef` don't need quotes.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    let mut cli_args = CliArgs::default();
+    let mut input = Vec::new();
+    let mut print_config = false;
+    let mut print_history = false;
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(Command::Help);
+        }
+        if arg == "--" {
+            input.extend(args.by_ref());
+            break;
+        }
+        if !arg.starts_with("--") {
+            input.push(arg);
+            continue;
+        }
+
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_owned(), Some(value.to_owned())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| CliError::MissingValue(name.clone()))
+        };
+
+        match name.as_str() {
+            "--literals" => cli_args.literals = true,
+            "--no-history" => cli_args.no_history = true,
+            "--print-config" => print_config = true,
+            "--history" => print_history = true,
+            "--config" => cli_args.config = Some(PathBuf::from(value()?)),
+            "--q" => {
+                let value = value()?;
+                cli_args.q_formats.extend(
+                    parse_q_formats(&value)
+                        .ok_or_else(|| CliError::InvalidValue(name.clone(), value.clone()))?,
+                );
+            }
+            "--endian" => {
+                let value = value()?;
+                cli_args.endianness = Some(
+                    Endianness::from_input(&value.to_lowercase())
+                        .map_err(|_| CliError::InvalidValue(name.clone(), value))?,
+                );
+            }
+            "--color" => {
+                let value = value()?;
+                cli_args.color = ColorChoice::from_input(&value.to_lowercase())
+                    .ok_or_else(|| CliError::InvalidValue(name.clone(), value))?;
+            }
+            "--format" => {
+                let value = value()?;
+                cli_args.format = Format::from_input(&value.to_lowercase())
+                    .ok_or_else(|| CliError::InvalidValue(name.clone(), value))?;
+            }
+            "--export" => {
+                let value = value()?;
+                cli_args.export = Some(
+                    Format::from_input(&value.to_lowercase())
+                        .filter(|format| REPORT_FORMATS.contains(format))
+                        .ok_or_else(|| CliError::InvalidValue(name.clone(), value))?,
+                );
+            }
+            "--precision" => {
+                let value = value()?;
+                cli_args.precision = Some(
+                    Precision::from_input(&value.to_lowercase())
+                        .ok_or_else(|| CliError::InvalidValue(name.clone(), value))?,
+                );
+            }
+            "--width" => {
+                let value = value()?;
+                cli_args.width = Some(
+                    value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|width| [8, 16, 32, 64].contains(width))
+                        .ok_or_else(|| CliError::InvalidValue(name.clone(), value))?,
+                );
+            }
+            _ => return Err(CliError::UnknownOption(name)),
+        }
+    }
+
+    if print_config {
+        return Ok(Command::PrintConfig(cli_args.config));
+    }
+    if print_history {
+        return Ok(Command::History {
+            config: cli_args.config,
+            no_history: cli_args.no_history,
+        });
+    }
+    if input.is_empty() {
+        return Err(CliError::MissingInput);
+    }
+    cli_args.input = input.join(" ");
+    Ok(Command::Run(cli_args))
+}
+
+/// Parses the value of `--q`. An explicit format such as `q15` or `uq8.8`
+/// results in exactly this format. Without the prefix, such as in `12.4`,
+/// the signed and the unsigned format are returned.
+fn parse_q_formats(value: &str) -> Option<Vec<QFormat>> {
+    let value = value.to_lowercase();
+    if value.starts_with('q') || value.starts_with("uq") {
+        return QFormat::from_input(&value).ok().map(|format| vec![format]);
+    }
+    let format = QFormat::from_input(&format!("q{value}")).ok()?;
+    Some(vec![format, format.with_signedness(false)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_q_formats() {
+        let Command::Run(cli_args) = parse_args(args(&["-7", "--q", "12.4", "--q=uq8.8"])).unwrap()
+        else {
+            panic!("expected Command::Run");
+        };
+        assert_eq!("-7", cli_args.input);
+        assert_eq!(
+            vec!["Q12.4", "UQ12.4", "UQ8.8"],
+            cli_args
+                .q_formats
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            parse_args(args(&["5", "--q"])),
+            Err(CliError::MissingValue(_))
+        ));
+        assert!(matches!(
+            parse_args(args(&["5", "--q", "0.4"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_q_formats() {
+        let names = |value| {
+            parse_q_formats(value)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["Q1.15", "UQ1.15"], names("15"));
+        assert_eq!(vec!["Q1.15"], names("q15"));
+        assert_eq!(vec!["UQ8.8"], names("UQ8.8"));
+        assert!(parse_q_formats("uq").is_none());
+    }
+
+    #[test]
+    fn test_parse_args_export() {
+        let Command::Run(cli_args) = parse_args(args(&["-", "--export", "MD"])).unwrap() else {
+            panic!("expected Command::Run");
+        };
+        assert_eq!("-", cli_args.input);
+        assert_eq!(Some(Format::Markdown), cli_args.export);
+        assert!(matches!(
+            parse_args(args(&["5", "--export", "json"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_format() {
+        assert!(matches!(
+            parse_args(args(&["5", "--format", "xml"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_precision() {
+        let Command::Run(cli_args) = parse_args(args(&["5", "--precision=Exact"])).unwrap() else {
+            panic!("expected Command::Run");
+        };
+        assert_eq!(Some(Precision::Exact), cli_args.precision);
+        assert!(matches!(
+            parse_args(args(&["5", "--precision", "fixed"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_commands() {
+        assert!(matches!(parse_args(args(&["5", "-h"])), Ok(Command::Help)));
+        assert!(matches!(
+            parse_args(args(&["--history"])),
+            Ok(Command::History {
+                config: None,
+                no_history: false
+            })
+        ));
+        assert!(matches!(
+            parse_args(args(&[
+                "--no-history",
+                "--history",
+                "--config",
+                "wambo.toml"
+            ])),
+            Ok(Command::History {
+                config: Some(_),
+                no_history: true
+            })
+        ));
+        assert!(matches!(
+            parse_args(args(&["--print-config", "--config=wambo.toml"])),
+            Ok(Command::PrintConfig(Some(_)))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_end_of_options() {
+        let Command::Run(cli_args) =
+            parse_args(args(&["--format", "plain", "--", "--2", "--q"])).unwrap()
+        else {
+            panic!("expected Command::Run");
+        };
+        assert_eq!("--2 --q", cli_args.input);
+        assert_eq!(Format::Plain, cli_args.format);
+        assert!(cli_args.q_formats.is_empty());
+        assert!(matches!(
+            parse_args(args(&["--"])),
+            Err(CliError::MissingInput)
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(matches!(parse_args(args(&[])), Err(CliError::MissingInput)));
+        assert!(matches!(
+            parse_args(args(&["5", "--foo"])),
+            Err(CliError::UnknownOption(_))
+        ));
+        assert!(matches!(
+            parse_args(args(&["5", "--color", "sometimes"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_args_byte_sequence() {
+        let Command::Run(cli_args) = parse_args(args(&[
+            "de",
+            "ad",
+            "be",
+            "ef",
+            "--endian",
+            "BE",
+            "--width=64",
+            "--color=never",
+            "--format",
+            "JSON",
+        ]))
+        .unwrap() else {
+            panic!("expected Command::Run");
+        };
+        assert_eq!("de ad be ef", cli_args.input);
+        assert_eq!(Some(Endianness::Big), cli_args.endianness);
+        assert_eq!(Some(64), cli_args.width);
+        assert!(!cli_args.literals);
+        assert_eq!(ColorChoice::Never, cli_args.color);
+        assert_eq!(Format::Json, cli_args.format);
+        assert_eq!(None, cli_args.export);
+
+        assert!(matches!(
+            parse_args(args(&["5", "--width", "12"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse_args(args(&["5", "--endian", "middle"])),
+            Err(CliError::InvalidValue(_, _))
+        ));
+    }
+}
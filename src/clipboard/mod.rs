@@ -0,0 +1,103 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Copying text to the system clipboard with the OSC 52 escape sequence of
+//! the terminal. This works over SSH and without a display server, as the
+//! terminal emulator sets the clipboard.
+
+use std::io;
+use std::io::Write;
+
+/// Alphabet of the standard base64 encoding.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Copies the text to the clipboard by writing the escape sequence to the
+/// terminal. Inside tmux, the sequence is passed through to the outer
+/// terminal. Terminals without OSC 52 support ignore the sequence.
+pub fn copy(terminal: &mut impl Write, text: &str) -> io::Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some_and(|value| !value.is_empty());
+    terminal.write_all(osc52_sequence(text, in_tmux).as_bytes())?;
+    terminal.flush()
+}
+
+/// Creates the OSC 52 sequence that sets the clipboard to `text`. tmux
+/// requires the sequence to be wrapped in a DCS passthrough, with all
+/// escape characters doubled.
+fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+/// Encodes the bytes with standard base64 and padding.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let block = chunk.iter().enumerate().fold(0_u32, |block, (i, byte)| {
+            block | u32::from(*byte) << (16 - 8 * i)
+        });
+        // a chunk of n bytes results in n + 1 characters
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (block >> (18 - 6 * i)) & 0x3f;
+                encoded.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!("", base64_encode(b""));
+        assert_eq!("Zg==", base64_encode(b"f"));
+        assert_eq!("Zm8=", base64_encode(b"fo"));
+        assert_eq!("Zm9v", base64_encode(b"foo"));
+        assert_eq!("Zm9vYmFy", base64_encode(b"foobar"));
+        assert_eq!("MHg0MGEwMDAwMA==", base64_encode(b"0x40a00000"));
+        assert_eq!("//79", base64_encode(&[0xff, 0xfe, 0xfd]));
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!("\x1b]52;c;NDI=\x07", osc52_sequence("42", false));
+        assert_eq!(
+            "\x1bPtmux;\x1b\x1b]52;c;NDI=\x07\x1b\\",
+            osc52_sequence("42", true)
+        );
+        let mut terminal = Vec::new();
+        copy(&mut terminal, "42").unwrap();
+        assert!(String::from_utf8(terminal).unwrap().contains("]52;c;NDI="));
+    }
+}
@@ -0,0 +1,544 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The user configuration file. It uses a small subset of TOML: `key = value`
+//! lines with strings, integers, booleans, and arrays of strings, `#`
+//! comments, and `[section]` headers.
+
+mod theme;
+
+pub use theme::{ColorChoice, Colors, Theme};
+
+use crate::parse::Endianness;
+use crate::print::{
+    DEFAULT_PRECISION, FormatOptions, Interpretation, Language, MAX_PRECISION, Precision,
+};
+use derive_more::Display;
+use ratatui::style::Color;
+use std::error::Error;
+use std::fmt::{Display as FmtDisplay, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+/// All supported keys. Keys in sections are prefixed with the section name.
+const KEYS: [&str; 13] = [
+    "precision",
+    "groups",
+    "endianness",
+    "width",
+    "digit_grouping",
+    "history",
+    "theme",
+    "literal_language",
+    "colors.title",
+    "colors.text",
+    "colors.background",
+    "colors.accent",
+    "colors.error",
+];
+
+/// Colors from the `[colors]` section. They override the colors of the
+/// theme.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ColorOverrides {
+    /// Titles of the groups.
+    pub title: Option<Color>,
+    /// Keys and values.
+    pub text: Option<Color>,
+    /// Background.
+    pub background: Option<Color>,
+    /// Highlighted text, such as links.
+    pub accent: Option<Color>,
+    /// Error messages.
+    pub error: Option<Color>,
+}
+
+/// The user configuration. Options from the command line take precedence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// How floats and sizes are formatted.
+    pub precision: Precision,
+    /// The groups that are shown in the TUI, in this order.
+    pub groups: Vec<Interpretation>,
+    /// Default byte order.
+    pub endianness: Endianness,
+    /// Default width in bits. If `None`, the width is derived from the input.
+    pub width: Option<u32>,
+    /// Separate groups of digits in the numeral systems with `_`.
+    pub digit_grouping: bool,
+    /// Read and write the history file.
+    pub history: bool,
+    /// Color theme of the TUI. If `None`, the theme is detected.
+    pub theme: Option<Theme>,
+    /// Language of the literals that are copied in the TUI.
+    pub literal_language: Language,
+    /// Colors of the TUI that override the theme.
+    pub colors: ColorOverrides,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            precision: Precision::Max(DEFAULT_PRECISION),
+            groups: Interpretation::ALL.to_vec(),
+            endianness: Endianness::default(),
+            width: None,
+            digit_grouping: false,
+            history: true,
+            theme: None,
+            literal_language: Language::default(),
+            colors: ColorOverrides::default(),
+        }
+    }
+}
+
+/// Errors in the config file.
+#[derive(Debug, Display)]
+pub enum ConfigError {
+    /// The config file can't be read.
+    #[display("{_0}")]
+    Io(io::Error),
+    /// The line is not valid syntax.
+    #[display("line {_0}: {_1}")]
+    Syntax(usize, String),
+    /// The key is not supported.
+    #[display("line {_0}: unknown key `{_1}`, supported keys are: {}", KEYS.join(", "))]
+    UnknownKey(usize, String),
+    /// The value is not valid for the key.
+    #[display("line {_0}: invalid value for `{_1}`: {_2}")]
+    InvalidValue(usize, String, String),
+}
+
+impl Error for ConfigError {}
+
+/// A value of the config file.
+#[derive(Debug, PartialEq, Eq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<String>),
+}
+
+impl Config {
+    /// Loads the config file from `path`, or from [`Self::default_path`] if
+    /// `None`. A missing file at the default location results in the
+    /// default config.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let (path, is_explicit) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match Self::default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => content.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !is_explicit => Ok(Self::default()),
+            Err(e) => Err(ConfigError::Io(e)),
+        }
+    }
+
+    /// The default location of the config file: `wambo/config.toml` in
+    /// `$XDG_CONFIG_HOME` (default `~/.config`).
+    pub fn default_path() -> Option<PathBuf> {
+        let non_empty_env = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+        non_empty_env("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty_env("HOME").map(|home| Path::new(&home).join(".config")))
+            .map(|config_dir| config_dir.join("wambo").join("config.toml"))
+    }
+
+    /// How values are formatted, from `precision` and `digit_grouping`.
+    pub const fn format_options(&self) -> FormatOptions {
+        FormatOptions {
+            precision: self.precision,
+            digit_grouping: self.digit_grouping,
+        }
+    }
+
+    /// The colors of the TUI for the `--color` choice. `never`, or `auto`
+    /// with `NO_COLOR` set, result in the monochrome theme. A theme from
+    /// the config file takes precedence over `NO_COLOR`.
+    pub fn colors(&self, choice: ColorChoice) -> Colors {
+        let theme = match (choice, self.theme) {
+            (ColorChoice::Never, _) => return Theme::Monochrome.colors(),
+            (_, Some(theme)) => theme,
+            (ColorChoice::Auto, None) if theme::no_color() => return Theme::Monochrome.colors(),
+            (_, None) => Theme::detect(),
+        };
+        let colors = theme.colors();
+        Colors {
+            title: self.colors.title.unwrap_or(colors.title),
+            text: self.colors.text.unwrap_or(colors.text),
+            background: self.colors.background.unwrap_or(colors.background),
+            accent: self.colors.accent.unwrap_or(colors.accent),
+            error: self.colors.error.unwrap_or(colors.error),
+            monochrome: colors.monochrome,
+        }
+    }
+
+    /// Applies a single `key = value` line.
+    fn set(&mut self, line: usize, key: &str, value: Value) -> Result<(), ConfigError> {
+        let invalid =
+            |message: &str| ConfigError::InvalidValue(line, key.to_owned(), message.to_owned());
+        match (key, value) {
+            ("precision", Value::Integer(precision)) => {
+                self.precision = u8::try_from(precision)
+                    .ok()
+                    .filter(|precision| *precision <= MAX_PRECISION)
+                    .map(Precision::Max)
+                    .ok_or_else(|| invalid(&format!("expected 0 to {MAX_PRECISION}")))?;
+            }
+            ("precision", Value::String(precision)) => {
+                self.precision = Precision::from_input(&precision.to_lowercase())
+                    .filter(|precision| !matches!(precision, Precision::Max(_)))
+                    .ok_or_else(|| {
+                        invalid(&format!(
+                            "expected \"fixed:N\" with N from 0 to {MAX_PRECISION}, \
+                             \"shortest\", \"exact\", or \"hex\""
+                        ))
+                    })?;
+            }
+            ("groups", Value::Array(ids)) => {
+                let mut groups = Vec::new();
+                for id in ids {
+                    let group = Interpretation::from_id(&id).ok_or_else(|| {
+                        let ids = Interpretation::ALL.map(Interpretation::id);
+                        invalid(&format!(
+                            "unknown group `{id}`, supported groups are: {}",
+                            ids.join(", ")
+                        ))
+                    })?;
+                    if groups.contains(&group) {
+                        return Err(invalid(&format!("group `{id}` is listed twice")));
+                    }
+                    groups.push(group);
+                }
+                self.groups = groups;
+            }
+            ("endianness", Value::String(endianness)) => {
+                self.endianness = Endianness::from_input(&endianness.to_lowercase())
+                    .map_err(|_| invalid("expected \"little\" or \"big\""))?;
+            }
+            ("width", Value::Integer(width)) => {
+                self.width = Some(
+                    u32::try_from(width)
+                        .ok()
+                        .filter(|width| [8, 16, 32, 64].contains(width))
+                        .ok_or_else(|| invalid("expected 8, 16, 32, or 64"))?,
+                );
+            }
+            ("digit_grouping", Value::Boolean(digit_grouping)) => {
+                self.digit_grouping = digit_grouping;
+            }
+            ("history", Value::Boolean(history)) => self.history = history,
+            ("theme", Value::String(theme)) => {
+                self.theme = match theme.as_str() {
+                    "auto" => None,
+                    theme => Some(Theme::from_input(theme).ok_or_else(|| {
+                        let themes = Theme::ALL.map(|theme| theme.to_string());
+                        invalid(&format!("expected \"auto\", {}", themes.join(", ")))
+                    })?),
+                };
+            }
+            ("literal_language", Value::String(language)) => {
+                self.literal_language = Language::from_input(&language.to_lowercase())
+                    .ok_or_else(|| invalid("expected \"rust\", \"c\", or \"python\""))?;
+            }
+            (
+                "colors.title" | "colors.text" | "colors.background" | "colors.accent"
+                | "colors.error",
+                Value::String(color),
+            ) => {
+                let color = Color::from_str(&color).map_err(|_| {
+                    invalid("expected a color name, such as \"blue\", or \"#rrggbb\"")
+                })?;
+                let color = Some(color);
+                match key {
+                    "colors.title" => self.colors.title = color,
+                    "colors.text" => self.colors.text = color,
+                    "colors.background" => self.colors.background = color,
+                    "colors.accent" => self.colors.accent = color,
+                    _ => self.colors.error = color,
+                }
+            }
+            (key, _) if KEYS.contains(&key) => return Err(invalid("wrong type")),
+            (key, _) => return Err(ConfigError::UnknownKey(line, key.to_owned())),
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut config = Self::default();
+        let mut section = String::new();
+        let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        while let Some((line_number, line)) = lines.next() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                section = format!("{}.", name.trim());
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                ConfigError::Syntax(line_number, format!("expected `key = value`: {line}"))
+            })?;
+            let key = format!("{section}{}", key.trim());
+
+            // arrays may span multiple lines
+            let mut value = value.trim().to_owned();
+            while value.starts_with('[') && !value.ends_with(']') {
+                let (_, next_line) = lines.next().ok_or_else(|| {
+                    ConfigError::Syntax(line_number, format!("unterminated array for `{key}`"))
+                })?;
+                value.push_str(strip_comment(next_line).trim());
+            }
+
+            let value = parse_value(&value).ok_or_else(|| {
+                ConfigError::Syntax(line_number, format!("invalid value: {value}"))
+            })?;
+            config.set(line_number, &key, value)?;
+        }
+        Ok(config)
+    }
+}
+
+/// Writes the config in the format of the config file.
+impl FmtDisplay for Config {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.precision {
+            Precision::Max(digits) => writeln!(f, "precision = {digits}")?,
+            precision => writeln!(f, "precision = \"{precision}\"")?,
+        }
+        writeln!(f, "groups = [")?;
+        for group in &self.groups {
+            writeln!(f, "    \"{}\",", group.id())?;
+        }
+        writeln!(f, "]")?;
+        let endianness = match self.endianness {
+            Endianness::Little => "little",
+            Endianness::Big => "big",
+        };
+        writeln!(f, "endianness = \"{endianness}\"")?;
+        match self.width {
+            Some(width) => writeln!(f, "width = {width}")?,
+            None => writeln!(f, "# width = 64")?,
+        }
+        writeln!(f, "digit_grouping = {}", self.digit_grouping)?;
+        writeln!(f, "history = {}", self.history)?;
+        match self.theme {
+            Some(theme) => writeln!(f, "theme = \"{theme}\"")?,
+            None => writeln!(f, "theme = \"auto\"")?,
+        }
+        let language = self.literal_language.to_string().to_lowercase();
+        writeln!(f, "literal_language = \"{language}\"")?;
+        writeln!(f)?;
+
+        // colors that are not set are shown with the value from the theme
+        writeln!(f, "[colors]")?;
+        let theme_colors = self.theme.unwrap_or_default().colors();
+        let colors = [
+            ("title", self.colors.title, theme_colors.title),
+            ("text", self.colors.text, theme_colors.text),
+            (
+                "background",
+                self.colors.background,
+                theme_colors.background,
+            ),
+            ("accent", self.colors.accent, theme_colors.accent),
+            ("error", self.colors.error, theme_colors.error),
+        ];
+        for (key, color, theme_color) in colors {
+            let prefix = if color.is_some() { "" } else { "# " };
+            let color = color.unwrap_or(theme_color).to_string().to_lowercase();
+            writeln!(f, "{prefix}{key} = \"{color}\"")?;
+        }
+        Ok(())
+    }
+}
+
+/// Removes a `#` comment, unless the `#` is part of a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(value: &str) -> Option<Value> {
+    if let Some(elements) = value.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        return elements
+            .split(',')
+            .map(str::trim)
+            .filter(|element| !element.is_empty())
+            .map(parse_string)
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array);
+    }
+    match value {
+        "true" => Some(Value::Boolean(true)),
+        "false" => Some(Value::Boolean(false)),
+        _ => parse_string(value)
+            .map(Value::String)
+            .or_else(|| value.replace('_', "").parse().ok().map(Value::Integer)),
+    }
+}
+
+fn parse_string(value: &str) -> Option<String> {
+    value
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|s| !s.contains('"'))
+        .map(ToOwned::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = r##"
+            # wambo config
+            precision = 8
+            groups = [
+                "ieee754", # floats first
+                "numeral-systems",
+            ]
+            endianness = "big"
+            width = 32
+            digit_grouping = true
+            history = false
+            theme = "light"
+            literal_language = "Python"
+
+            [colors]
+            title = "#ff8800"
+            background = "reset"
+        "##
+        .parse::<Config>()
+        .unwrap();
+        assert_eq!(
+            Config {
+                precision: Precision::Max(8),
+                groups: vec![Interpretation::IEEE754, Interpretation::NumeralSystems],
+                endianness: Endianness::Big,
+                width: Some(32),
+                digit_grouping: true,
+                history: false,
+                theme: Some(Theme::Light),
+                literal_language: Language::Python,
+                colors: ColorOverrides {
+                    title: Some(Color::Rgb(0xff, 0x88, 0x00)),
+                    background: Some(Color::Reset),
+                    ..ColorOverrides::default()
+                },
+            },
+            config
+        );
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let error = |content: &str| content.parse::<Config>().unwrap_err().to_string();
+        assert!(error("\nprecison = 4").starts_with("line 2: unknown key `precison`"));
+        assert!(error("[theme]\ntitle = \"blue\"").contains("unknown key `theme.title`"));
+        assert_eq!(
+            "line 1: invalid value for `precision`: expected 0 to 20",
+            error("precision = 99")
+        );
+        assert_eq!(
+            "line 1: invalid value for `precision`: expected \"fixed:N\" with N from 0 to 20, \
+             \"shortest\", \"exact\", or \"hex\"",
+            error("precision = \"4\"")
+        );
+        assert!(error("precision = \"fixed:21\"").contains("expected \"fixed:N\""));
+        assert!(error("precision = true").contains("wrong type"));
+        assert!(error("groups = [\"foo\"]").contains("unknown group `foo`"));
+        assert!(error("groups = [\"bits\", \"bits\"]").contains("listed twice"));
+        assert!(error("width = 12").contains("expected 8, 16, 32, or 64"));
+        assert!(error("[colors]\ntext = \"blurple\"").contains("expected a color name"));
+        assert!(error("theme = \"solarized\"").contains("expected \"auto\", dark, light"));
+        assert_eq!("line 1: expected `key = value`: history", error("history"));
+        assert!(error("groups = [\n\"bits\"").contains("unterminated array"));
+    }
+
+    #[test]
+    fn test_print_config() {
+        let config = Config {
+            precision: Precision::Exact,
+            width: Some(16),
+            theme: Some(Theme::HighContrast),
+            literal_language: Language::C,
+            colors: ColorOverrides {
+                accent: Some(Color::Indexed(42)),
+                ..ColorOverrides::default()
+            },
+            ..Config::default()
+        };
+        assert_eq!(config, config.to_string().parse().unwrap());
+        let config = Config {
+            precision: Precision::Fixed(8),
+            ..Config::default()
+        };
+        assert_eq!(config, config.to_string().parse().unwrap());
+        assert_eq!(
+            Config::default(),
+            Config::default().to_string().parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_config_colors() {
+        let config = Config {
+            theme: Some(Theme::Light),
+            colors: ColorOverrides {
+                accent: Some(Color::Green),
+                ..ColorOverrides::default()
+            },
+            ..Config::default()
+        };
+        let colors = config.colors(ColorChoice::Auto);
+        assert_eq!(Color::Black, colors.text);
+        assert_eq!(Color::Green, colors.accent);
+        assert_eq!(
+            Theme::Monochrome.colors(),
+            config.colors(ColorChoice::Never)
+        );
+        assert_eq!(colors, config.colors(ColorChoice::Always));
+    }
+}
@@ -0,0 +1,200 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Color themes of the TUI and the `--color` choice.
+
+use derive_more::Display;
+use ratatui::style::Color;
+
+/// Colors of the TUI.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Colors {
+    /// Titles of the groups.
+    pub title: Color,
+    /// Keys and values.
+    pub text: Color,
+    /// Background.
+    pub background: Color,
+    /// Highlighted text, such as links.
+    pub accent: Color,
+    /// Error messages.
+    pub error: Color,
+    /// No colors at all. Accents are underlined instead and color swatches
+    /// are replaced by shades.
+    pub monochrome: bool,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Theme::Dark.colors()
+    }
+}
+
+/// The built-in color themes.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Display)]
+pub enum Theme {
+    /// Light text on a dark background.
+    #[default]
+    #[display("dark")]
+    Dark,
+    /// Dark text on a light background.
+    #[display("light")]
+    Light,
+    /// Bright colors on black.
+    #[display("high-contrast")]
+    HighContrast,
+    /// The default colors of the terminal, without any colors.
+    #[display("monochrome")]
+    Monochrome,
+}
+
+impl Theme {
+    /// All themes.
+    pub const ALL: [Self; 4] = [
+        Self::Dark,
+        Self::Light,
+        Self::HighContrast,
+        Self::Monochrome,
+    ];
+
+    /// Parses the [`Theme`] from its name, such as `high-contrast`.
+    pub fn from_input(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.to_string() == name)
+    }
+
+    /// The colors of the theme.
+    pub const fn colors(self) -> Colors {
+        match self {
+            Self::Dark => Colors {
+                title: Color::Blue,
+                text: Color::White,
+                background: Color::Black,
+                accent: Color::Yellow,
+                error: Color::Red,
+                monochrome: false,
+            },
+            Self::Light => Colors {
+                title: Color::Blue,
+                text: Color::Black,
+                background: Color::White,
+                accent: Color::Magenta,
+                error: Color::Red,
+                monochrome: false,
+            },
+            Self::HighContrast => Colors {
+                title: Color::LightYellow,
+                text: Color::White,
+                background: Color::Black,
+                accent: Color::LightCyan,
+                error: Color::LightRed,
+                monochrome: false,
+            },
+            Self::Monochrome => Colors {
+                title: Color::Reset,
+                text: Color::Reset,
+                background: Color::Reset,
+                accent: Color::Reset,
+                error: Color::Reset,
+                monochrome: true,
+            },
+        }
+    }
+
+    /// Detects a fitting theme from the background color of the terminal.
+    /// Only works for terminals that set `COLORFGBG`, such as rxvt and
+    /// Konsole; defaults to [`Theme::Dark`].
+    pub fn detect() -> Self {
+        std::env::var("COLORFGBG")
+            .ok()
+            .and_then(|value| Self::from_colorfgbg(&value))
+            .unwrap_or_default()
+    }
+
+    /// Parses a `COLORFGBG` value, such as `15;0`, whose last field is the
+    /// ANSI index of the background color.
+    fn from_colorfgbg(value: &str) -> Option<Self> {
+        let background = value.rsplit(';').next()?.parse::<u8>().ok()?;
+        match background {
+            7 | 9..=15 => Some(Self::Light),
+            0..=6 | 8 => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+
+/// Whether wambo uses colors, passed via `--color`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Display)]
+pub enum ColorChoice {
+    /// Colors unless `NO_COLOR` is set.
+    #[default]
+    #[display("auto")]
+    Auto,
+    /// Colors, even if `NO_COLOR` is set.
+    #[display("always")]
+    Always,
+    /// No colors.
+    #[display("never")]
+    Never,
+}
+
+impl ColorChoice {
+    /// Parses the [`ColorChoice`] from a string, such as `never`.
+    pub fn from_input(choice: &str) -> Option<Self> {
+        match choice {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Checks if the user disabled colors via `NO_COLOR` (see <https://no-color.org>).
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_from_input() {
+        for theme in Theme::ALL {
+            assert_eq!(Some(theme), Theme::from_input(&theme.to_string()));
+        }
+        assert_eq!(None, Theme::from_input("solarized"));
+    }
+
+    #[test]
+    fn test_theme_from_colorfgbg() {
+        assert_eq!(Some(Theme::Dark), Theme::from_colorfgbg("15;0"));
+        assert_eq!(Some(Theme::Light), Theme::from_colorfgbg("0;15"));
+        assert_eq!(Some(Theme::Light), Theme::from_colorfgbg("0;default;7"));
+        assert_eq!(None, Theme::from_colorfgbg("15;default"));
+        assert_eq!(None, Theme::from_colorfgbg(""));
+    }
+}
@@ -0,0 +1,334 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Persistent history of the inputs, so that earlier values can be recalled
+//! in the TUI and referenced in new inputs, such as `$_ + 0x10`.
+
+use crate::parse::ParsedUserInput;
+use derive_more::Display;
+use regex::{Captures, Regex};
+use std::error::Error;
+use std::net::Ipv6Addr;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// Maximum number of entries in the history file. Older entries are dropped.
+pub const MAX_ENTRIES: usize = 1000;
+/// Inputs that are longer than this are not added to the history.
+const MAX_INPUT_LEN: usize = 256;
+
+/// Environment variable that overrides the location of the history file.
+const HISTORY_FILE_ENV: &str = "WAMBO_HISTORY_FILE";
+/// Environment variable that disables the history, if set to a non-empty
+/// value.
+const NO_HISTORY_ENV: &str = "WAMBO_NO_HISTORY";
+
+/// References to earlier results: `$_` is the previous result and `$3` is
+/// the third entry of the history.
+pub const REFERENCE_REGEX: &str = "\\$(?P<reference>_|[0-9]+)";
+
+/// A single input of the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// The result, as input that can be parsed again, such as `-0x10`.
+    /// Values wider than 64 bit are stored as IPv6 address, such as
+    /// `fe80::1`, as this is the only input for them that doesn't depend on
+    /// the byte order and width.
+    value: String,
+    /// The input, as typed by the user, with resolved references.
+    input: String,
+}
+
+impl HistoryEntry {
+    /// Getter for `value`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Getter for `input`.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+/// Errors when resolving references to the history.
+#[derive(Debug, Display)]
+pub enum HistoryError {
+    /// The input references the history, but it is disabled.
+    #[display("{_0} can't be resolved, as the history is disabled")]
+    Disabled(String),
+    /// The referenced entry doesn't exist.
+    #[display("{_0} is not in the history ({_1} entries)")]
+    UnknownReference(String, usize),
+}
+
+impl Error for HistoryError {}
+
+/// The history, loaded from the history file. A history without a file is
+/// disabled: it has no entries and nothing is saved.
+#[derive(Debug, Default)]
+pub struct History {
+    path: Option<PathBuf>,
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Loads the history from the file at `path`. A missing file results in
+    /// an empty history. With `None`, the history is disabled.
+    pub fn load(path: Option<PathBuf>) -> io::Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let entries = content
+            .lines()
+            .filter_map(|line| {
+                let (value, input) = line.split_once('\t')?;
+                Some(HistoryEntry {
+                    value: value.to_owned(),
+                    input: input.to_owned(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut history = Self {
+            path: Some(path),
+            entries,
+        };
+        history.truncate();
+        Ok(history)
+    }
+
+    /// The default location of the history file: `$WAMBO_HISTORY_FILE`, or
+    /// `wambo/history` in `$XDG_STATE_HOME` (default `~/.local/state`).
+    /// Returns `None` if the history is disabled via `$WAMBO_NO_HISTORY` or
+    /// no location is known.
+    pub fn default_path() -> Option<PathBuf> {
+        let non_empty_env = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+        if non_empty_env(NO_HISTORY_ENV).is_some() {
+            return None;
+        }
+        if let Some(path) = non_empty_env(HISTORY_FILE_ENV) {
+            return Some(PathBuf::from(path));
+        }
+        non_empty_env("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty_env("HOME").map(|home| Path::new(&home).join(".local/state")))
+            .map(|state_dir| state_dir.join("wambo").join("history"))
+    }
+
+    /// Getter for `path`.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Getter for `entries`. The oldest entry comes first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Replaces references to earlier results with their values: `$_` is
+    /// the previous result and `$1`, `$2`, ... are the entries as numbered
+    /// by `--history`.
+    pub fn resolve_references(&self, input: &str) -> Result<String, HistoryError> {
+        let mut error = None;
+        let regex = Regex::new(REFERENCE_REGEX).unwrap();
+        let resolved = regex.replace_all(input, |captures: &Captures| {
+            let reference = &captures["reference"];
+            let entry = if reference == "_" {
+                self.entries.last()
+            } else {
+                reference
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|number| number.checked_sub(1))
+                    .and_then(|index| self.entries.get(index))
+            };
+            entry.map_or_else(
+                || {
+                    let reference = captures[0].to_owned();
+                    error.get_or_insert_with(|| {
+                        if self.path.is_none() {
+                            HistoryError::Disabled(reference)
+                        } else {
+                            HistoryError::UnknownReference(reference, self.entries.len())
+                        }
+                    });
+                    String::new()
+                },
+                |entry| entry.value.clone(),
+            )
+        });
+        error.map_or_else(|| Ok(resolved.into_owned()), Err)
+    }
+
+    /// Adds the input and its result to the history. Repeated inputs and
+    /// very long inputs are skipped. Does nothing if the history is disabled.
+    pub fn add(&mut self, input: &str, parsed: &ParsedUserInput) {
+        // the file is line-based with tab separated fields
+        let input = input.split_whitespace().collect::<Vec<_>>().join(" ");
+        if self.path.is_none()
+            || input.is_empty()
+            || input.len() > MAX_INPUT_LEN
+            || self
+                .entries
+                .last()
+                .is_some_and(|entry| entry.input == input)
+        {
+            return;
+        }
+        let value = parsed.wide_bits().map_or_else(
+            || format!("{}0x{:x}", parsed.sign(), parsed.value()),
+            |bits| Ipv6Addr::from(bits).to_string(),
+        );
+        self.entries.push(HistoryEntry { value, input });
+        self.truncate();
+    }
+
+    /// Writes the history file. Does nothing if the history is disabled.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content = self
+            .entries
+            .iter()
+            .map(|entry| format!("{}\t{}\n", entry.value, entry.input))
+            .collect::<String>();
+        fs::write(path, content)
+    }
+
+    fn truncate(&mut self) {
+        let excess = self.entries.len().saturating_sub(MAX_ENTRIES);
+        self.entries.drain(..excess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+
+    fn add(history: &mut History, input: &str) {
+        let parsed = parse_input(input, &Default::default()).unwrap();
+        history.add(input, &parsed);
+    }
+
+    #[test]
+    fn test_history() {
+        let path = std::env::temp_dir().join(format!("wambo-history-test-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(Some(path.clone())).unwrap();
+        assert!(history.entries().is_empty());
+        add(&mut history, "0x40a00000");
+        add(&mut history, "-16");
+        add(&mut history, "-16");
+        add(&mut history, &format!("1{}", "_".repeat(MAX_INPUT_LEN)));
+        history.save().unwrap();
+
+        let history = History::load(Some(path.clone())).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            vec![("0x40a00000", "0x40a00000"), ("-0x10", "-16")],
+            history
+                .entries()
+                .iter()
+                .map(|entry| (entry.value(), entry.input()))
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            "-0x10 + 0x10",
+            history.resolve_references("$_ + 0x10").unwrap()
+        );
+        assert_eq!(
+            "0x40a00000 - -0x10",
+            history.resolve_references("$1 - $2").unwrap()
+        );
+        assert!(matches!(
+            history.resolve_references("$3"),
+            Err(HistoryError::UnknownReference(_, 2))
+        ));
+    }
+
+    #[test]
+    fn test_wide_values() {
+        let mut history = History {
+            path: Some(PathBuf::new()),
+            entries: Vec::new(),
+        };
+        for input in ["fe80::1", "00 11 22 33 44 55 66 77 88 99 aa bb cc dd ee ff"] {
+            add(&mut history, input);
+            let expected = parse_input(input, &Default::default()).unwrap();
+            let recalled = history.resolve_references("$_").unwrap();
+            let recalled = parse_input(&recalled, &Default::default()).unwrap();
+            assert_eq!(expected.wide_bits(), recalled.wide_bits());
+            assert_eq!(expected.value(), recalled.value());
+            // arithmetic would drop the upper 64 bits
+            let sum = history.resolve_references("$_ + 1").unwrap();
+            assert!(parse_input(&sum, &Default::default()).is_err());
+        }
+        assert_eq!(
+            vec!["fe80::1", "ffee:ddcc:bbaa:9988:7766:5544:3322:1100"],
+            history
+                .entries()
+                .iter()
+                .map(HistoryEntry::value)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_disabled_history() {
+        let mut history = History::load(None).unwrap();
+        add(&mut history, "5");
+        assert!(history.entries().is_empty());
+        history.save().unwrap();
+        assert_eq!("5", history.resolve_references("5").unwrap());
+        assert!(matches!(
+            history.resolve_references("$_"),
+            Err(HistoryError::Disabled(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut history = History {
+            path: Some(PathBuf::new()),
+            entries: Vec::new(),
+        };
+        for i in 0..MAX_ENTRIES + 10 {
+            add(&mut history, &i.to_string());
+        }
+        assert_eq!(MAX_ENTRIES, history.entries().len());
+        assert_eq!("10", history.entries()[0].input());
+    }
+}
@@ -0,0 +1,224 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! State of the interactive view and how it reacts to keys. It doesn't know
+//! about the terminal or the event loop, so that key sequences can be
+//! scripted in tests.
+
+use super::*;
+use crate::print::Language;
+use crossterm::event::KeyEvent;
+
+/// What the event loop has to do after a key.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Effect {
+    None,
+    /// Stop the interactive view.
+    Quit,
+    /// Copy the text to the clipboard and report the result via
+    /// [`App::copied`].
+    Copy(String),
+}
+
+/// The interactive view: the input, the bit editor, the open overlays, and
+/// the [`ViewState`].
+#[derive(Debug)]
+pub struct App<'a> {
+    user_input: &'a ParsedUserInput,
+    options: &'a OutputOptions,
+    input_options: &'a InputOptions,
+    history: &'a History,
+    config: &'a Config,
+    colors: &'a Colors,
+    /// The input recalled from the history; `None` until the first recall.
+    recalled_input: Option<ParsedUserInput>,
+    editor: BitEditor,
+    /// The value from the bit editor; `None` until the first edit.
+    edited_input: Option<ParsedUserInput>,
+    history_view: Option<HistoryView>,
+    show_help: bool,
+    state: ViewState,
+    /// Language of copied literals.
+    language: Language,
+    /// Height of the last frame, for scrolling by pages.
+    height: u16,
+}
+
+impl<'a> App<'a> {
+    pub fn new(
+        user_input: &'a ParsedUserInput,
+        options: &'a OutputOptions,
+        input_options: &'a InputOptions,
+        history: &'a History,
+        config: &'a Config,
+        colors: &'a Colors,
+    ) -> Self {
+        Self {
+            user_input,
+            options,
+            input_options,
+            history,
+            config,
+            colors,
+            recalled_input: None,
+            editor: BitEditor::new(user_input.bits()),
+            edited_input: None,
+            history_view: None,
+            show_help: false,
+            state: ViewState {
+                format: config.format_options(),
+                ..Default::default()
+            },
+            language: config.literal_language,
+            height: 0,
+        }
+    }
+
+    /// Draws the main view and the open overlays.
+    pub fn draw(&mut self, f: &mut Frame) {
+        self.height = f.area().height;
+        let base_input = self.recalled_input.as_ref().unwrap_or(self.user_input);
+        let current_input = self.edited_input.as_ref().unwrap_or(base_input);
+        draw_tui(
+            f,
+            current_input,
+            self.options,
+            &self.editor,
+            self.config,
+            self.colors,
+            &mut self.state,
+        );
+        if let Some(history_view) = &self.history_view {
+            draw_history_view(f, history_view, self.colors);
+        }
+        if self.show_help {
+            draw_help_view(f, self.colors);
+        }
+    }
+
+    /// Reacts to the key. Keys go to the open overlay first; the remaining
+    /// keys move the focus, scroll, or edit the value in the bit editor.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Effect {
+        if key.kind != KeyEventKind::Press {
+            return Effect::None;
+        }
+        // in raw mode, CTRL+C is a key event and not a signal
+        let is_ctrl_c =
+            key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+        if is_ctrl_c {
+            return Effect::Quit;
+        }
+
+        if let Some(view) = &mut self.history_view {
+            match view.handle_key(key.code) {
+                HistoryAction::None => {}
+                HistoryAction::Close => self.history_view = None,
+                HistoryAction::Recall(input) => match parse_input(&input, self.input_options) {
+                    Ok(parsed) => {
+                        self.editor = BitEditor::new(parsed.bits());
+                        self.edited_input = None;
+                        self.recalled_input = Some(parsed);
+                        self.history_view = None;
+                    }
+                    Err(e) => view.set_error(e.to_string()),
+                },
+            }
+            return Effect::None;
+        }
+        if self.show_help {
+            self.show_help = !matches!(key.code, KeyCode::Esc | KeyCode::Char('?' | 'q'));
+            return Effect::None;
+        }
+
+        if key.code == KeyCode::Char('q') {
+            return Effect::Quit;
+        }
+        self.state.message = None;
+        let base_input = self.recalled_input.as_ref().unwrap_or(self.user_input);
+        let current_input = self.edited_input.as_ref().unwrap_or(base_input);
+        let line_counts = line_counts(&self.config.groups, current_input, self.options);
+        if self.state.focus.handle_key(key.code, &line_counts) {
+            self.state.reveal_focus = true;
+            return Effect::None;
+        }
+        // scroll by half a page, so that some context remains visible
+        let page = self.height / 2;
+        match key.code {
+            KeyCode::PageDown => self.state.scroll = self.state.scroll.saturating_add(page),
+            KeyCode::PageUp => self.state.scroll = self.state.scroll.saturating_sub(page),
+            KeyCode::Char('?') => self.show_help = true,
+            KeyCode::Char(key @ ('y' | 'Y')) => {
+                let (groups, focus, format) =
+                    (&self.config.groups, self.state.focus, &self.state.format);
+                let text = if key == 'Y' {
+                    selected_literal(
+                        groups,
+                        focus,
+                        current_input,
+                        self.options,
+                        format,
+                        self.language,
+                    )
+                } else {
+                    selected_value(groups, focus, current_input, self.options, format)
+                };
+                if let Some(text) = text {
+                    return Effect::Copy(text);
+                }
+            }
+            KeyCode::Char('L') => {
+                self.language = self.language.next();
+                self.state.message = Some(format!("Literals are copied as {}", self.language));
+            }
+            KeyCode::Char('p') => {
+                let format = &mut self.state.format;
+                format.precision = format.precision.next(self.config.precision);
+                self.state.message = Some(format!(
+                    "Precision of floats and sizes: {}",
+                    format.precision
+                ));
+            }
+            KeyCode::Char('H') => self.history_view = Some(HistoryView::new(self.history, false)),
+            KeyCode::Char('/') => self.history_view = Some(HistoryView::new(self.history, true)),
+            KeyCode::Char('r') => {
+                self.editor = BitEditor::new(base_input.bits());
+                self.edited_input = None;
+            }
+            code => {
+                if self.editor.handle_key(code) {
+                    self.edited_input = Some(ParsedUserInput::from_bits(self.editor.bits()));
+                }
+            }
+        }
+        Effect::None
+    }
+
+    /// Shows the result of copying `text` to the clipboard.
+    pub fn copied(&mut self, text: &str, result: io::Result<()>) {
+        self.state.message = Some(match result {
+            Ok(()) => format!("Copied {text}"),
+            Err(e) => format!("Can't copy: {e}"),
+        });
+    }
+}
@@ -0,0 +1,220 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The interactive bit editor: its state and drawing.
+
+use super::*;
+use crate::config::Colors;
+use crate::print::Interpretation;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+/// Key bindings of the bit editor, shown as hint in the TUI.
+pub const BIT_EDITOR_KEYS: &str =
+    "space toggle, ←→ move, ↑↓ ±1, <> shift, ,. rotate, s swap bytes, i invert, r reset";
+
+/// The 64 bits of the value and a cursor that selects a single bit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BitEditor {
+    bits: u64,
+    /// Index of the selected bit; `0` is the least significant bit.
+    cursor: u32,
+}
+
+impl BitEditor {
+    pub const fn new(bits: u64) -> Self {
+        Self { bits, cursor: 0 }
+    }
+
+    /// Getter for `bits`.
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Getter for `cursor`.
+    pub const fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    /// Applies the action that is bound to the key. Returns `false` if the
+    /// key is not bound.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Left | KeyCode::Char('h') => self.cursor = (self.cursor + 1).min(63),
+            KeyCode::Right | KeyCode::Char('l') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Char(' ') => self.bits ^= 1 << self.cursor,
+            KeyCode::Up => self.bits = self.bits.wrapping_add(1),
+            KeyCode::Down => self.bits = self.bits.wrapping_sub(1),
+            KeyCode::Char('<') => self.bits <<= 1,
+            KeyCode::Char('>') => self.bits >>= 1,
+            KeyCode::Char(',') => self.bits = self.bits.rotate_left(1),
+            KeyCode::Char('.') => self.bits = self.bits.rotate_right(1),
+            KeyCode::Char('s') => self.bits = self.bits.swap_bytes(),
+            KeyCode::Char('i') => self.bits = !self.bits,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Prefix of the lines of the bit grid, so that the bits are aligned.
+const KEY_WIDTH: usize = 6;
+
+/// Width of the bit grid with all 8 bytes in one line.
+const FULL_GRID_WIDTH: usize = KEY_WIDTH + 64 + 7;
+
+/// Draws the bits as a grid with the cursor, followed by the value in hex
+/// and the key bindings.
+pub fn draw_bit_editor(
+    buf: &mut Buffer,
+    parent_rect: Rect,
+    editor: &BitEditor,
+    colors: &Colors,
+    focused: bool,
+) {
+    bit_editor_widget(editor, colors, parent_rect.width, focused).render(parent_rect, buf)
+}
+
+/// Creates the widget of the bit editor. If the width is too small for all
+/// 8 bytes in one line, the grid is split into two lines of 4 bytes each.
+/// If the bit editor is focused, its hex value is the selected line.
+pub fn bit_editor_widget(
+    editor: &BitEditor,
+    colors: &Colors,
+    width: u16,
+    focused: bool,
+) -> Paragraph<'static> {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let bits_per_line: u32 = if usize::from(width) >= FULL_GRID_WIDTH {
+        64
+    } else {
+        32
+    };
+
+    let mut text = Vec::new();
+    // the bits, most significant first, grouped by bytes; each line of the
+    // grid is followed by a line with the cursor marker
+    for line_start in (0..64).step_by(bits_per_line as usize) {
+        let key = if line_start == 0 { "Bits:" } else { "" };
+        let mut bit_spans = vec![Span::styled(format!("{key:KEY_WIDTH$}"), bold)];
+        let line_indices = 64 - line_start - bits_per_line..64 - line_start;
+        for index in line_indices.clone().rev() {
+            let bit = if editor.bits() & (1 << index) == 0 {
+                "0"
+            } else {
+                "1"
+            };
+            let style = if index == editor.cursor() {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            bit_spans.push(Span::styled(bit, style));
+            if index % 8 == 0 && index != line_indices.start {
+                bit_spans.push(Span::raw(" "));
+            }
+        }
+        text.push(Line::from(bit_spans));
+
+        let cursor = editor.cursor();
+        if line_indices.contains(&cursor) {
+            let position = (line_indices.end - 1 - cursor) as usize;
+            let cursor_column = KEY_WIDTH + position + position / 8;
+            let label = format!("bit {} (byte {})", cursor, cursor / 8);
+            // the label goes left of the marker if it doesn't fit right of it
+            let marker = if cursor_column + 2 + label.len() <= usize::from(width) {
+                format!("{:cursor_column$}^ {label}", "")
+            } else {
+                let label_end = cursor_column - 1;
+                format!("{label:>label_end$} ^")
+            };
+            text.push(Line::from(marker));
+        } else {
+            text.push(Line::default());
+        }
+    }
+    text.push(Line::from(vec![
+        Span::styled(format!("{:KEY_WIDTH$}", "Hex:"), bold),
+        Span::styled(
+            format!("0x{:016x}", editor.bits()),
+            if focused {
+                selected_style()
+            } else {
+                Style::default()
+            },
+        ),
+        Span::raw("  "),
+        Span::raw(BIT_EDITOR_KEYS),
+    ]));
+
+    Paragraph::new(text)
+        .block(group_block(Interpretation::Bit64BigEndian, colors, focused))
+        .style(
+            Style::default()
+                .fg(colors.text)
+                .bg(colors.background)
+                // remove inherited bold from block styling
+                .remove_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Left)
+        // the key bindings wrap on narrow terminals
+        .wrap(Wrap { trim: false })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_key() {
+        let mut editor = BitEditor::new(0x40a0_0000);
+        assert!(editor.handle_key(KeyCode::Char(' ')));
+        assert_eq!(0x40a0_0001, editor.bits());
+        editor.handle_key(KeyCode::Left);
+        editor.handle_key(KeyCode::Char(' '));
+        assert_eq!(0x40a0_0003, editor.bits());
+        editor.handle_key(KeyCode::Down);
+        editor.handle_key(KeyCode::Char('<'));
+        assert_eq!(0x8140_0004, editor.bits());
+        editor.handle_key(KeyCode::Char('s'));
+        assert_eq!(0x0400_4081_0000_0000, editor.bits());
+        editor.handle_key(KeyCode::Char('.'));
+        editor.handle_key(KeyCode::Char(','));
+        editor.handle_key(KeyCode::Char('i'));
+        assert_eq!(!0x0400_4081_0000_0000, editor.bits());
+        assert!(!editor.handle_key(KeyCode::Char('x')));
+
+        for _ in 0..100 {
+            editor.handle_key(KeyCode::Left);
+        }
+        assert_eq!(63, editor.cursor());
+        editor.handle_key(KeyCode::Char('l'));
+        assert_eq!(62, editor.cursor());
+        editor.handle_key(KeyCode::Char('h'));
+        assert_eq!(63, editor.cursor());
+        editor.handle_key(KeyCode::Char('>'));
+        editor.handle_key(KeyCode::Up);
+        assert_eq!((!0x0400_4081_0000_0000_u64 >> 1) + 1, editor.bits());
+    }
+}
@@ -0,0 +1,252 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The focused group and the selected line in it.
+
+use crate::ParsedUserInput;
+use crate::parse::NumeralSystem;
+use crate::print::{
+    FormatOptions, Integer, Interpretation, Language, OutputOptions, Value, get_output_group,
+};
+use crossterm::event::KeyCode;
+
+/// The focused group, as index into the shown groups, and the selected
+/// line in it.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Focus {
+    group: usize,
+    line: usize,
+}
+
+impl Focus {
+    /// Getter for `group`.
+    pub const fn group(&self) -> usize {
+        self.group
+    }
+
+    /// Getter for `line`.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Moves the focus for the key. `line_counts` holds the number of lines
+    /// of each shown group. Returns `false`, if the key doesn't move the
+    /// focus.
+    pub fn handle_key(&mut self, key: KeyCode, line_counts: &[usize]) -> bool {
+        if line_counts.is_empty() {
+            return false;
+        }
+        let groups = line_counts.len();
+        // the input might have changed since the last key
+        self.group = self.group.min(groups - 1);
+        let last_line = |group: usize| line_counts[group].saturating_sub(1);
+        self.line = self.line.min(last_line(self.group));
+
+        match key {
+            KeyCode::Tab => *self = Self::at((self.group + 1) % groups, 0),
+            KeyCode::BackTab => *self = Self::at((self.group + groups - 1) % groups, 0),
+            // j and k continue in the next and the previous group
+            KeyCode::Char('j') if self.line < last_line(self.group) => self.line += 1,
+            KeyCode::Char('j') if self.group + 1 < groups => *self = Self::at(self.group + 1, 0),
+            KeyCode::Char('k') if self.line > 0 => self.line -= 1,
+            KeyCode::Char('k') if self.group > 0 => {
+                *self = Self::at(self.group - 1, last_line(self.group - 1));
+            }
+            KeyCode::Char('j' | 'k') => {}
+            KeyCode::Char('g') => self.line = 0,
+            KeyCode::Char('G') => self.line = last_line(self.group),
+            _ => return false,
+        }
+        true
+    }
+
+    const fn at(group: usize, line: usize) -> Self {
+        Self { group, line }
+    }
+}
+
+/// The formatted value of the selected line, without alignment padding.
+/// For the bit editor, this is its value in hex.
+pub fn selected_value(
+    groups: &[Interpretation],
+    focus: Focus,
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+    format: &FormatOptions,
+) -> Option<String> {
+    selected_line_value(groups, focus, user_input, options).map(|value| value.format(format))
+}
+
+/// The value of the selected line as source code literal in the language.
+/// Integers keep their numeral system, such as `0b101010`.
+pub fn selected_literal(
+    groups: &[Interpretation],
+    focus: Focus,
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+    format: &FormatOptions,
+    language: Language,
+) -> Option<String> {
+    selected_line_value(groups, focus, user_input, options)
+        .map(|value| language.value_literal(&value, format))
+}
+
+/// The typed value of the selected line.
+fn selected_line_value(
+    groups: &[Interpretation],
+    focus: Focus,
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+) -> Option<Value> {
+    match groups.get(focus.group)? {
+        Interpretation::Bit64BigEndian => Some(Value::Integer(Integer {
+            value: i128::from(user_input.bits()),
+            width: 64,
+            signed: false,
+            radix: NumeralSystem::Hex,
+            padded: true,
+            grouped: Some(false),
+        })),
+        interpretation => get_output_group(user_input, *interpretation, options)
+            .lines()
+            .get(focus.line)
+            .map(|line| line.value().clone()),
+    }
+}
+
+/// The number of selectable lines of each group. The bit editor only has
+/// its hex value.
+pub fn line_counts(
+    groups: &[Interpretation],
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+) -> Vec<usize> {
+    groups
+        .iter()
+        .map(|interpretation| match interpretation {
+            Interpretation::Bit64BigEndian => 1,
+            _ => get_output_group(user_input, *interpretation, options).line_count(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_key() {
+        let line_counts = [2, 1, 3];
+        let mut focus = Focus::default();
+        assert!(focus.handle_key(KeyCode::Char('j'), &line_counts));
+        assert_eq!(Focus::at(0, 1), focus);
+        focus.handle_key(KeyCode::Char('j'), &line_counts);
+        assert_eq!(Focus::at(1, 0), focus);
+        focus.handle_key(KeyCode::Tab, &line_counts);
+        focus.handle_key(KeyCode::Char('G'), &line_counts);
+        assert_eq!(Focus::at(2, 2), focus);
+        // the last line stays selected
+        focus.handle_key(KeyCode::Char('j'), &line_counts);
+        assert_eq!(Focus::at(2, 2), focus);
+        focus.handle_key(KeyCode::Tab, &line_counts);
+        assert_eq!(Focus::at(0, 0), focus);
+        focus.handle_key(KeyCode::BackTab, &line_counts);
+        focus.handle_key(KeyCode::Char('k'), &line_counts);
+        assert_eq!(Focus::at(1, 0), focus);
+        focus.handle_key(KeyCode::Char('k'), &line_counts);
+        assert_eq!(Focus::at(0, 1), focus);
+        assert!(!focus.handle_key(KeyCode::Char('x'), &line_counts));
+        assert!(!focus.handle_key(KeyCode::Tab, &[]));
+        // fewer lines after a new input
+        assert!(focus.handle_key(KeyCode::Char('g'), &[1]));
+        assert_eq!(Focus::at(0, 0), focus);
+    }
+
+    #[test]
+    fn test_selected_value() {
+        let parsed = crate::parse::parse_input("0x40a00000", &Default::default()).unwrap();
+        let groups = [
+            Interpretation::NumeralSystems,
+            Interpretation::Bit64BigEndian,
+        ];
+        let options = OutputOptions::default();
+        let value = |focus| selected_value(&groups, focus, &parsed, &options, &Default::default());
+        assert_eq!(Some("1084227584".to_owned()), value(Focus::at(0, 0)));
+        assert_eq!(Some("40a00000".to_owned()), value(Focus::at(0, 3)));
+        assert_eq!(
+            Some("0x0000000040a00000".to_owned()),
+            value(Focus::at(1, 0))
+        );
+        assert_eq!(None, value(Focus::at(0, 4)));
+        assert_eq!(None, value(Focus::at(2, 0)));
+    }
+
+    #[test]
+    fn test_selected_literal() {
+        let parsed = crate::parse::parse_input("0x40a00000", &Default::default()).unwrap();
+        let groups = [
+            Interpretation::NumeralSystems,
+            Interpretation::Bit64BigEndian,
+        ];
+        let options = OutputOptions::default();
+        let literal = |focus, language| {
+            selected_literal(
+                &groups,
+                focus,
+                &parsed,
+                &options,
+                &Default::default(),
+                language,
+            )
+            .unwrap()
+        };
+        assert_eq!("1084227584", literal(Focus::at(0, 0), Language::Rust));
+        assert_eq!(
+            "0b1000000101000000000000000000000",
+            literal(Focus::at(0, 1), Language::Python)
+        );
+        assert_eq!("0o10050000000", literal(Focus::at(0, 2), Language::Rust));
+        assert_eq!("010050000000", literal(Focus::at(0, 2), Language::C));
+        assert_eq!("0x40a00000", literal(Focus::at(0, 3), Language::C));
+        assert_eq!(
+            "0x0000000040a00000",
+            literal(Focus::at(1, 0), Language::Rust)
+        );
+
+        let parsed = crate::parse::parse_input("-0x2a", &Default::default()).unwrap();
+        let grouping = FormatOptions {
+            digit_grouping: true,
+            ..Default::default()
+        };
+        let hex = selected_literal(
+            &groups,
+            Focus::at(0, 3),
+            &parsed,
+            &options,
+            &grouping,
+            Language::Rust,
+        );
+        assert_eq!(Some("-0x2a".to_owned()), hex);
+    }
+}
@@ -0,0 +1,94 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The help overlay of the TUI with all key bindings.
+
+use super::*;
+use crate::config::Colors;
+use ratatui::widgets::Clear;
+
+/// All key bindings of the interactive view, as keys and description.
+pub const KEY_BINDINGS: [(&str, &str); 21] = [
+    ("q, Ctrl+C", "Quit."),
+    ("Tab/Shift+Tab", "Focus the next/previous group."),
+    ("j/k", "Select the next/previous line."),
+    ("g/G", "Select the first/last line of the group."),
+    (
+        "y",
+        "Copy the value of the selected line to the clipboard (OSC 52).",
+    ),
+    (
+        "Y",
+        "Copy the value of the selected line as source code literal.",
+    ),
+    (
+        "L",
+        "Switch the language of copied literals: Rust, C, Python.",
+    ),
+    (
+        "p",
+        "Switch the precision of floats and sizes: shortest, exact, hex.",
+    ),
+    (
+        "PgUp/PgDn",
+        "Scroll, if the terminal is too small for all groups.",
+    ),
+    ("Left/Right, h/l", "Move the cursor of the bit editor."),
+    ("Space", "Toggle the bit under the cursor."),
+    ("Up/Down", "Increment/decrement the value."),
+    ("< / >", "Shift left/right."),
+    (", / .", "Rotate left/right."),
+    ("s", "Swap the byte order."),
+    ("i", "Invert all bits."),
+    ("r", "Reset to the input value."),
+    ("H", "Show the history. Enter recalls the selected input."),
+    ("/", "Search the history."),
+    ("?", "Show this help."),
+    ("Esc", "Close the help or the history."),
+];
+
+pub fn draw_help_view(f: &mut Frame, colors: &Colors) {
+    let area = centered_rect(f.area(), 70, 80);
+    f.render_widget(Clear, area);
+
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let text = KEY_BINDINGS
+        .iter()
+        .map(|(keys, description)| {
+            Line::from(vec![
+                Span::styled(format!("{keys:<16}"), bold),
+                Span::raw(*description),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title("Keys (Esc: close)")
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(colors.text).bg(colors.background))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,204 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The history overlay of the TUI: a searchable list of earlier inputs.
+
+use super::*;
+use crate::config::Colors;
+use crate::history::History;
+use ratatui::widgets::{Clear, List, ListItem, ListState};
+
+/// What the TUI should do after a key press in the history overlay.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HistoryAction {
+    /// Nothing, the overlay stays open.
+    None,
+    /// Close the overlay.
+    Close,
+    /// Close the overlay and show the input.
+    Recall(String),
+}
+
+/// State of the history overlay.
+#[derive(Debug)]
+pub struct HistoryView {
+    /// The inputs of the history, the newest first.
+    inputs: Vec<String>,
+    query: String,
+    searching: bool,
+    /// Index of the selected line in [`Self::matches`].
+    selected: usize,
+    error: Option<String>,
+}
+
+impl HistoryView {
+    /// Creates the overlay. With `searching`, typed characters go to the
+    /// search query right away.
+    pub fn new(history: &History, searching: bool) -> Self {
+        Self {
+            inputs: history
+                .entries()
+                .iter()
+                .rev()
+                .map(|entry| entry.input().to_owned())
+                .collect(),
+            query: String::new(),
+            searching,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    /// The inputs that contain the search query, the newest first.
+    fn matches(&self) -> Vec<&str> {
+        let query = self.query.to_lowercase();
+        self.inputs
+            .iter()
+            .filter(|input| input.to_lowercase().contains(&query))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Shows an error, such as for an input that can't be parsed anymore.
+    pub fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> HistoryAction {
+        let match_count = self.matches().len();
+        match key {
+            KeyCode::Esc => return HistoryAction::Close,
+            KeyCode::Enter => {
+                return self
+                    .matches()
+                    .get(self.selected)
+                    .map_or(HistoryAction::None, |input| {
+                        HistoryAction::Recall(input.to_string())
+                    });
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(match_count.saturating_sub(1)),
+            KeyCode::Backspace if self.searching => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if self.searching => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            KeyCode::Char('/') => self.searching = true,
+            KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(match_count.saturating_sub(1))
+            }
+            KeyCode::Char('q') => return HistoryAction::Close,
+            _ => {}
+        }
+        self.error = None;
+        HistoryAction::None
+    }
+}
+
+/// Draws the history overlay in the center of the screen.
+pub fn draw_history_view(f: &mut Frame, view: &HistoryView, colors: &Colors) {
+    let area = centered_rect(f.area(), 60, 60);
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("History (Enter: recall, /: search, Esc: close)")
+        .borders(Borders::ALL)
+        .style(Style::default().fg(colors.text).bg(colors.background));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+        .split(inner_area);
+
+    let status = match &view.error {
+        Some(error) => Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(colors.error),
+        )),
+        None if view.searching => Line::from(vec![
+            Span::styled("Search: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("{}_", view.query)),
+        ]),
+        None => Line::from("Up/Down or j/k to select"),
+    };
+    f.render_widget(Paragraph::new(status), layout[0]);
+
+    let matches = view.matches();
+    let items = if view.inputs.is_empty() {
+        vec![ListItem::new("(the history is empty or disabled)")]
+    } else {
+        matches.iter().map(|input| ListItem::new(*input)).collect()
+    };
+    let list = List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state =
+        ListState::default().with_selected((!matches.is_empty()).then_some(view.selected));
+    f.render_stateful_widget(list, layout[1], &mut state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(inputs: &[&str], searching: bool) -> HistoryView {
+        HistoryView {
+            inputs: inputs.iter().map(ToString::to_string).collect(),
+            query: String::new(),
+            searching,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut view = view(&["0x10", "1mb", "0x40a00000"], false);
+        view.handle_key(KeyCode::Down);
+        view.handle_key(KeyCode::Char('j'));
+        view.handle_key(KeyCode::Down);
+        assert_eq!(
+            HistoryAction::Recall("0x40a00000".to_string()),
+            view.handle_key(KeyCode::Enter)
+        );
+
+        view.handle_key(KeyCode::Char('/'));
+        view.handle_key(KeyCode::Char('0'));
+        view.handle_key(KeyCode::Char('x'));
+        view.handle_key(KeyCode::Down);
+        assert_eq!(vec!["0x10", "0x40a00000"], view.matches());
+        assert_eq!(
+            HistoryAction::Recall("0x40a00000".to_string()),
+            view.handle_key(KeyCode::Enter)
+        );
+
+        view.handle_key(KeyCode::Char('z'));
+        assert_eq!(HistoryAction::None, view.handle_key(KeyCode::Enter));
+        assert_eq!(HistoryAction::Close, view.handle_key(KeyCode::Esc));
+    }
+}
@@ -25,11 +25,52 @@ SOFTWARE.
 
 use super::*;
 use crate::ParsedUserInput;
+use crate::config::Colors;
+use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::text::Line;
 
-pub fn draw_b0_block(f: &mut Frame, parent_rect: Rect, user_input: &ParsedUserInput) {
-    let layout_b0 = Layout::default()
+/// Below this width, the left and the right part are stacked.
+const SIDE_BY_SIDE_MIN_WIDTH: u16 = 80;
+
+/// The height of the B0 block for the given width, including one empty
+/// line as separator.
+pub fn b0_height(user_input: &ParsedUserInput, colors: &Colors, width: u16) -> u16 {
+    let left = left_widget(colors);
+    let right = right_widget(user_input, colors);
+    let height = if width >= SIDE_BY_SIDE_MIN_WIDTH {
+        let half_width = b0_layout(Rect::new(0, 0, width, 1))[0].width;
+        left.line_count(half_width)
+            .max(right.line_count(half_width))
+    } else {
+        left.line_count(width) + right.line_count(width)
+    };
+    height as u16 + 1
+}
+
+pub fn draw_b0_block(
+    buf: &mut Buffer,
+    parent_rect: Rect,
+    user_input: &ParsedUserInput,
+    colors: &Colors,
+) {
+    let left = left_widget(colors);
+    let right = right_widget(user_input, colors);
+    if parent_rect.width >= SIDE_BY_SIDE_MIN_WIDTH {
+        let layout_b0 = b0_layout(parent_rect);
+        left.render(layout_b0[0], buf);
+        right.render(layout_b0[2], buf);
+    } else {
+        let left_height = left.line_count(parent_rect.width) as u16;
+        let layout_b0 = Layout::vertical([Constraint::Length(left_height), Constraint::Min(0)])
+            .split(parent_rect);
+        left.render(layout_b0[0], buf);
+        right.render(layout_b0[1], buf);
+    }
+}
+
+fn b0_layout(parent_rect: Rect) -> Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Horizontal)
         .constraints(
             [
@@ -39,13 +80,10 @@ pub fn draw_b0_block(f: &mut Frame, parent_rect: Rect, user_input: &ParsedUserIn
             ]
             .as_ref(),
         )
-        .split(parent_rect);
-
-    draw_left(f, layout_b0[0]);
-    draw_right(f, layout_b0[2], user_input);
+        .split(parent_rect)
 }
 
-fn draw_left(f: &mut Frame, rect: Rect) {
+fn left_widget(colors: &Colors) -> Paragraph<'static> {
     let text = vec![
         Line::from(vec![Span::styled(
             format!("WAMBO (v{})", env!("CARGO_PKG_VERSION")),
@@ -53,28 +91,50 @@ fn draw_left(f: &mut Frame, rect: Rect) {
         )]),
         Line::from(vec![
             Span::raw("Web version: "),
-            Span::styled("https://wambo-web.de", Style::default().fg(Color::Yellow)),
+            Span::styled("https://wambo-web.de", accent_style(colors)),
         ]),
     ];
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title("").borders(Borders::NONE))
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+    Paragraph::new(text)
+        .style(Style::default().fg(colors.text).bg(colors.background))
         .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-
-    f.render_widget(paragraph, rect)
+        .wrap(Wrap { trim: true })
 }
 
-fn draw_right(f: &mut Frame, rect: Rect, user_input: &ParsedUserInput) {
-    let text = vec![Line::from(vec![
+fn right_widget<'a>(user_input: &'a ParsedUserInput, colors: &Colors) -> Paragraph<'a> {
+    let mut text = vec![Line::from(vec![
         Span::styled("Input: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(user_input.normalized_input()),
     ])];
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().title("").borders(Borders::NONE))
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+    if let Some(byte_sequence) = user_input.byte_sequence() {
+        text.push(Line::from(vec![
+            Span::styled("Bytes: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(byte_sequence.to_string()),
+        ]));
+    }
+    if let Some(varint) = user_input.varint() {
+        text.push(Line::from(vec![
+            Span::styled("Varint: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(varint.to_string()),
+        ]));
+    }
+    if user_input.wide_bits().is_some() {
+        text.push(Line::from(vec![
+            Span::styled("Width: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("128 bit (most groups show the lower 64 bits)"),
+        ]));
+    }
+    Paragraph::new(text)
+        .style(Style::default().fg(colors.text).bg(colors.background))
         .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: true })
+}
 
-    f.render_widget(paragraph, rect)
+/// Style of highlighted text. Without colors, it is underlined instead.
+fn accent_style(colors: &Colors) -> Style {
+    let style = Style::default().fg(colors.accent);
+    if colors.monochrome {
+        style.add_modifier(Modifier::UNDERLINED)
+    } else {
+        style
+    }
 }
@@ -24,149 +24,426 @@ SOFTWARE.
 
 //! Everything related to the [`ratatui`] layout.
 
+mod app;
+mod bit_editor;
+mod focus;
+mod help_view;
+mod history_view;
 mod layout_b0;
-mod layout_b1;
-mod layout_b2;
-mod layout_b3;
-mod layout_b4;
+mod rows;
+#[cfg(test)]
+mod snapshot_tests;
+mod swatches;
 
 use crate::ParsedUserInput;
-use crate::print::OutputGroup;
-use crossterm::event::{Event, KeyCode};
+use crate::SIGNAL_STOP;
+use crate::clipboard;
+use crate::config::{Colors, Config};
+use crate::history::History;
+use crate::parse::{InputOptions, parse_input};
+use crate::print::{FormatOptions, Interpretation, OutputGroup, OutputOptions};
+use crate::render::Renderer;
+use app::{App, Effect};
+use bit_editor::BitEditor;
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
 use crossterm::{event, execute};
+use focus::{Focus, line_counts, selected_literal, selected_value};
+pub use help_view::KEY_BINDINGS;
+use help_view::draw_help_view;
+use history_view::{HistoryAction, HistoryView, draw_history_view};
 use layout_b0::*;
-use layout_b1::*;
-use layout_b2::*;
-use layout_b3::*;
-use layout_b4::*;
 use ratatui::backend::{Backend, CrosstermBackend};
-use ratatui::layout::{Alignment, Constraint, Direction, Layout};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Paragraph, Widget, Wrap};
 use ratatui::{Frame, Terminal};
+use rows::{GroupContext, build_rows, draw_row, row_height, selection_span, split_row};
 use std::io;
+use std::rc::Rc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
-/// Displays the TUI and reacts to events, such as close.
-/// Wrapper around [`draw_tui`].
-pub fn run_tui<B: Backend>(
+/// How often the event loop checks [`SIGNAL_STOP`] while waiting for events.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Minimum width of the terminal. The bit editor needs 4 bytes per line.
+const MIN_WIDTH: u16 = 42;
+
+/// Minimum height of the terminal.
+const MIN_HEIGHT: u16 = 10;
+
+/// State of the main view that is kept between frames.
+#[derive(Debug, Default)]
+pub struct ViewState {
+    /// The first visible line; clamped to the content height when drawing.
+    scroll: u16,
+    /// The focused group and the selected line in it.
+    focus: Focus,
+    /// Scrolls the selected line into view at the next draw.
+    reveal_focus: bool,
+    /// Feedback for the last key, such as a copied value. Shown in the last
+    /// line until the next key.
+    message: Option<String>,
+    /// How values are formatted.
+    format: FormatOptions,
+}
+
+/// Displays the TUI and reacts to events, such as close, edits in the bit
+/// editor, or recalls from the history. The state is kept in an [`App`].
+pub fn run_tui<B: Backend + io::Write>(
     terminal: &mut Terminal<B>,
     user_input: &ParsedUserInput,
+    options: &OutputOptions,
+    input_options: &InputOptions,
+    history: &History,
+    config: &Config,
+    colors: &Colors,
 ) -> io::Result<()> {
+    let mut app = App::new(user_input, options, input_options, history, config, colors);
     loop {
-        terminal.draw(|f| draw_tui(f, user_input))?;
-
-        if let Event::Key(key) = event::read()? {
-            if key.code == KeyCode::Char('q') ||
-                // triggered when CTRL+C is pressed, as `crossterm` catches
-                // all events and signals are not delivered normally
-                key.code == KeyCode::Char('c')
-            {
-                return Ok(());
+        terminal.draw(|f| app.draw(f))?;
+
+        let Some(event) = next_event()? else {
+            return Ok(());
+        };
+        let key = match event {
+            Event::Key(key) => key,
+            // the next iteration redraws with the new size right away
+            Event::Resize(_, _) => {
+                terminal.autoresize()?;
+                continue;
+            }
+            _ => continue,
+        };
+        match app.handle_key(key) {
+            Effect::None => {}
+            Effect::Quit => return Ok(()),
+            Effect::Copy(text) => {
+                let result = clipboard::copy(terminal.backend_mut(), &text);
+                app.copied(&text, result);
             }
         }
     }
 }
 
-/// Draw's the TUI.
-pub fn draw_tui(f: &mut Frame, user_input: &ParsedUserInput) {
-    // Constructs the main layout with 4 blocks:
-    // - b0: heading block
-    // - b1: numeral systems / f32/f64
-    // - b2: kb / kib
-    // - b3: signed integers / unsigned integers
-    // - b4: bytes in memory
-    let layout_main = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(
-            [
-                Constraint::Length(4),
-                Constraint::Length(6),
-                Constraint::Length(7),
-                Constraint::Length(7),
-                Constraint::Length(4),
-            ]
-            .as_ref(),
-        )
-        .split(f.area());
-
-    let border_block = Block::default().borders(Borders::NONE);
+/// Draw's the TUI. The groups are arranged in as many columns as fit into
+/// the width of the terminal. If the content is higher than the terminal,
+/// only the part from the scroll position on is visible.
+pub fn draw_tui(
+    f: &mut Frame,
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+    editor: &BitEditor,
+    config: &Config,
+    colors: &Colors,
+    state: &mut ViewState,
+) {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        draw_too_small(f, colors);
+        return;
+    }
 
-    f.render_widget(border_block.clone(), layout_main[0]);
-    let inner_rect = border_block.clone().inner(layout_main[0]);
-    draw_b0_block(f, inner_rect, user_input);
+    // Constructs the main layout: the heading block (b0), followed by the
+    // rows of the groups from the config
+    let selected = config
+        .groups
+        .get(state.focus.group())
+        .map(|interpretation| (*interpretation, state.focus.line()));
+    let format = state.format;
+    let context = GroupContext {
+        user_input,
+        options,
+        format: &format,
+        editor,
+        colors,
+        selected,
+    };
+    let rows = build_rows(&config.groups, &context, area.width);
+    let heights = std::iter::once(b0_height(user_input, colors, area.width))
+        .chain(rows.iter().map(|row| row_height(row, &context, area.width)))
+        .collect::<Vec<_>>();
+    let content_height = heights.iter().sum::<u16>();
 
-    f.render_widget(border_block.clone(), layout_main[1]);
-    let inner_rect = border_block.clone().inner(layout_main[1]);
-    draw_b1_block(f, inner_rect, user_input);
+    // the content is drawn to a separate buffer of its full height, of
+    // which the visible part is copied to the frame
+    let mut content = Buffer::empty(Rect::new(0, 0, area.width, content_height));
+    let layout_main = Layout::vertical(heights.iter().map(|height| Constraint::Length(*height)))
+        .split(content.area);
+    draw_b0_block(&mut content, layout_main[0], user_input, colors);
+    for (row, rect) in rows.iter().zip(layout_main.iter().skip(1)) {
+        draw_row(&mut content, *rect, row, &context);
+    }
 
-    f.render_widget(border_block.clone(), layout_main[2]);
-    let inner_rect = border_block.clone().inner(layout_main[2]);
-    draw_b2_block(f, inner_rect, user_input);
+    // the last line shows the message or the scroll position, if not
+    // everything fits
+    let overflows = content_height > area.height;
+    let has_status = overflows || state.message.is_some();
+    let visible_height = if has_status {
+        area.height - 1
+    } else {
+        area.height
+    };
+    if state.reveal_focus {
+        state.reveal_focus = false;
+        let focused_rect = rows
+            .iter()
+            .zip(layout_main.iter().skip(1))
+            .find_map(|(row, rect)| {
+                let position = row.iter().position(|(interpretation, _)| {
+                    Some(*interpretation) == selected.map(|s| s.0)
+                })?;
+                Some(split_row(*rect, row)[position])
+            });
+        if let Some(rect) = focused_rect {
+            let (start, end) = selection_span(rect, &context);
+            if start < state.scroll {
+                state.scroll = start;
+            } else if end > state.scroll + visible_height {
+                state.scroll = end - visible_height;
+            }
+        }
+    }
+    let scroll = state
+        .scroll
+        .min(content_height.saturating_sub(visible_height));
+    state.scroll = scroll;
+    for y in 0..visible_height.min(content_height) {
+        for x in 0..area.width {
+            f.buffer_mut()[(area.x + x, area.y + y)] = content[(x, y + scroll)].clone();
+        }
+    }
+    if has_status {
+        let status = state.message.as_ref().map_or_else(
+            || {
+                format!(
+                    " lines {}-{} of {}, PgUp/PgDn to scroll ",
+                    scroll + 1,
+                    scroll + visible_height,
+                    content_height
+                )
+            },
+            |message| format!(" {message} "),
+        );
+        let rect = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+        let paragraph = Paragraph::new(status).style(
+            Style::default()
+                .fg(colors.text)
+                .bg(colors.background)
+                .add_modifier(Modifier::REVERSED),
+        );
+        f.render_widget(paragraph, rect);
+    }
+}
 
-    f.render_widget(border_block.clone(), layout_main[3]);
-    let inner_rect = border_block.clone().inner(layout_main[3]);
-    draw_b3_block(f, inner_rect, user_input);
+/// Replaces the TUI by a notice, if the terminal is too small to show
+/// anything useful.
+fn draw_too_small(f: &mut Frame, colors: &Colors) {
+    let area = f.area();
+    let text = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "{}x{}, needs {}x{}",
+            area.width, area.height, MIN_WIDTH, MIN_HEIGHT
+        )),
+    ];
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().fg(colors.text).bg(colors.background))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    let rect = Layout::vertical([Constraint::Length(2)])
+        .flex(ratatui::layout::Flex::Center)
+        .split(area)[0];
+    f.render_widget(paragraph, rect);
+}
 
-    f.render_widget(border_block.clone(), layout_main[3]);
-    let inner_rect = border_block.clone().inner(layout_main[4]);
-    draw_b4_block(f, inner_rect, user_input);
+/// Waits for the next event of the terminal. Returns `None`, if a signal
+/// requested to stop, see [`SIGNAL_STOP`].
+fn next_event() -> io::Result<Option<Event>> {
+    // waits in short intervals, as signals don't interrupt the waiting
+    while !SIGNAL_STOP.load(Ordering::Relaxed) {
+        if event::poll(EVENT_POLL_INTERVAL)? {
+            return event::read().map(Some);
+        }
+    }
+    Ok(None)
 }
 
+/// Switches the terminal to raw mode and the alternate screen. From now on,
+/// panics and the signals SIGINT, SIGTERM, and SIGHUP restore the terminal.
 pub fn tui_prepare() -> io::Result<Terminal<impl Backend + io::Write>> {
+    install_panic_hook();
+    install_signal_handlers()?;
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
     let backend = CrosstermBackend::new(stdout);
     Terminal::new(backend)
 }
 
-pub fn tui_cleanup(mut terminal: Terminal<impl Backend + io::Write>) -> io::Result<()> {
-    // restore terminal
+pub fn tui_cleanup(terminal: Terminal<impl Backend + io::Write>) -> io::Result<()> {
+    drop(terminal);
+    restore_terminal()
+}
+
+/// Leaves raw mode and the alternate screen and shows the cursor again.
+fn restore_terminal() -> io::Result<()> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()
-}
-
-/// Transforms the lines of an [`OutputGroup`] to a [`ratatui`]-compatible [`Paragraph`].
-fn output_group_to_widget(output_group: &OutputGroup) -> Paragraph<'_> {
-    let text = output_group
-        .iter()
-        // map each (key,value) pair to a Span
-        .map(|(key, value)| {
-            Line::from(vec![
-                Span::styled(
-                    format!("{}: ", key),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ),
-                Span::raw(value),
-            ])
-        })
-        .collect::<Vec<_>>();
+    execute!(io::stdout(), LeaveAlternateScreen, Show)
+}
 
-    Paragraph::new(text)
-        .block(
-            Block::default()
-                .title(format!("{}", output_group.title()))
-                .borders(Borders::NONE)
-                .style(
-                    Style::default()
-                        .add_modifier(Modifier::BOLD)
-                        .fg(Color::Blue),
-                ),
-        )
+/// Restores the terminal before the panic message is printed. Otherwise,
+/// the message would be lost on the alternate screen.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // nothing else can be done, if this fails
+        let _ = restore_terminal();
+        default_hook(info);
+    }));
+}
+
+/// Sets [`SIGNAL_STOP`] on SIGINT, SIGTERM, and SIGHUP, so that the event
+/// loop stops and the terminal is restored.
+#[cfg(unix)]
+fn install_signal_handlers() -> io::Result<()> {
+    use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+    let mut signals = signal_hook::iterator::Signals::new([SIGINT, SIGTERM, SIGHUP])?;
+    std::thread::spawn(move || {
+        if signals.forever().next().is_some() {
+            SIGNAL_STOP.store(true, Ordering::Relaxed);
+        }
+    });
+    Ok(())
+}
+
+/// Only CTRL+C stops wambo on other platforms, which is a key event in raw
+/// mode.
+#[cfg(not(unix))]
+const fn install_signal_handlers() -> io::Result<()> {
+    Ok(())
+}
+
+/// Renders [`OutputGroup`]s as [`ratatui`]-compatible [`Paragraph`]s, one
+/// per group.
+#[derive(Debug, Copy, Clone)]
+pub struct TuiRenderer<'a> {
+    pub colors: &'a Colors,
+    pub format: &'a FormatOptions,
+    /// The focused group and the index of the selected line in it.
+    pub selected: Option<(Interpretation, usize)>,
+}
+
+impl TuiRenderer<'_> {
+    /// Transforms the lines of an [`OutputGroup`] to a [`Paragraph`].
+    fn widget(&self, output_group: &OutputGroup) -> Paragraph<'static> {
+        let selected = self
+            .selected
+            .filter(|(interpretation, _)| *interpretation == output_group.title())
+            .map(|(_, line)| line);
+        let text = output_group
+            .iter(self.format)
+            .enumerate()
+            // map each (key,value) pair to a Span
+            .map(|(index, (key, value))| {
+                let line = Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", key),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(value),
+                ]);
+                if selected == Some(index) {
+                    line.style(selected_style())
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Paragraph::new(text)
+            .block(group_block(
+                output_group.title(),
+                self.colors,
+                selected.is_some(),
+            ))
+            .style(
+                Style::default()
+                    .fg(self.colors.text)
+                    .bg(self.colors.background)
+                    // remove inherited bold from block styling
+                    .remove_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Left)
+            // don't trim whitespaces, as this will break alignment
+            .wrap(Wrap { trim: false })
+    }
+}
+
+impl Renderer for TuiRenderer<'_> {
+    type Output = Vec<Paragraph<'static>>;
+
+    fn render(&self, groups: &[OutputGroup]) -> Self::Output {
+        groups.iter().map(|group| self.widget(group)).collect()
+    }
+}
+
+/// The block around a group with its title. The title of the focused group
+/// is highlighted.
+fn group_block(title: Interpretation, colors: &Colors, focused: bool) -> Block<'static> {
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::NONE)
         .style(
             Style::default()
-                .fg(Color::White)
-                .bg(Color::Black)
-                // remove inherited bold from block styling
-                .remove_modifier(Modifier::BOLD),
+                .add_modifier(Modifier::BOLD)
+                .fg(colors.title),
+        );
+    if focused {
+        block.title_style(selected_style())
+    } else {
+        block
+    }
+}
+
+/// Style of the selected line in the focused group.
+fn selected_style() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+/// Returns a rectangle in the center of `area` with the given size in
+/// percent of `area`.
+fn centered_rect(area: Rect, width_percent: u16, height_percent: u16) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Percentage((100 - height_percent) / 2),
+                Constraint::Percentage(height_percent),
+                Constraint::Percentage((100 - height_percent) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage((100 - width_percent) / 2),
+                Constraint::Percentage(width_percent),
+                Constraint::Percentage((100 - width_percent) / 2),
+            ]
+            .as_ref(),
         )
-        .alignment(Alignment::Left)
-        // don't trim whitespaces, as this will break alignment
-        .wrap(Wrap { trim: false })
+        .split(vertical[1])[1]
 }
@@ -0,0 +1,285 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The rows of output groups below the heading block. As many groups as fit
+//! are shown side by side; wide groups take a row on their own.
+
+use super::*;
+use crate::ParsedUserInput;
+use crate::config::Colors;
+use crate::layout::bit_editor::{bit_editor_widget, draw_bit_editor};
+use crate::layout::swatches::{colors_text_width, draw_colors};
+use crate::print::{FormatOptions, Interpretation, OutputOptions, get_output_group};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::rc::Rc;
+
+/// Maximum number of groups side by side.
+const MAX_COLUMNS: usize = 4;
+
+/// Space between groups side by side.
+const COLUMN_GAP: u16 = 2;
+
+/// Everything that is needed to draw the groups.
+#[derive(Debug, Copy, Clone)]
+pub struct GroupContext<'a> {
+    pub user_input: &'a ParsedUserInput,
+    pub options: &'a OutputOptions,
+    pub format: &'a FormatOptions,
+    pub editor: &'a BitEditor,
+    pub colors: &'a Colors,
+    /// The focused group and the selected line in it.
+    pub selected: Option<(Interpretation, usize)>,
+}
+
+impl GroupContext<'_> {
+    /// The renderer for the groups, with the selection.
+    const fn renderer(&self) -> TuiRenderer<'_> {
+        TuiRenderer {
+            colors: self.colors,
+            format: self.format,
+            selected: self.selected,
+        }
+    }
+
+    /// The selected line, if the group is focused.
+    fn selected_line(&self, interpretation: Interpretation) -> Option<usize> {
+        self.selected
+            .filter(|(selected, _)| *selected == interpretation)
+            .map(|(_, line)| line)
+    }
+}
+
+/// A row of the layout: one or more groups side by side, each with the
+/// width of its widest line.
+pub type Row = Vec<(Interpretation, u16)>;
+
+/// Groups with long lines that need the full width.
+const fn is_wide(interpretation: Interpretation) -> bool {
+    matches!(
+        interpretation,
+        Interpretation::Bit64BigEndian | Interpretation::Literals | Interpretation::Colors
+    )
+}
+
+/// Arranges the groups in rows for the given width, keeping their order.
+pub fn build_rows(groups: &[Interpretation], context: &GroupContext, width: u16) -> Vec<Row> {
+    let groups = groups
+        .iter()
+        .map(|interpretation| {
+            let output_group =
+                get_output_group(context.user_input, *interpretation, context.options);
+            (
+                *interpretation,
+                output_group.text_width(context.format) as u16,
+            )
+        })
+        .collect::<Vec<_>>();
+    pack_rows(&groups, width)
+}
+
+/// Puts groups side by side as long as their widths fit into `width`.
+fn pack_rows(groups: &[(Interpretation, u16)], width: u16) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut row: Row = Vec::new();
+    for (interpretation, group_width) in groups.iter().copied() {
+        let row_width = row.iter().map(|(_, width)| width + COLUMN_GAP).sum::<u16>();
+        let fits =
+            !is_wide(interpretation) && row.len() < MAX_COLUMNS && row_width + group_width <= width;
+        if !fits && !row.is_empty() {
+            rows.push(std::mem::take(&mut row));
+        }
+        row.push((interpretation, group_width));
+        if is_wide(interpretation) {
+            rows.push(std::mem::take(&mut row));
+        }
+    }
+    if !row.is_empty() {
+        rows.push(row);
+    }
+    rows
+}
+
+/// Splits the rect of the row into one rect per group. The space is
+/// distributed according to the widths of the groups.
+pub fn split_row(rect: Rect, row: &Row) -> Rc<[Rect]> {
+    Layout::horizontal(row.iter().map(|(_, width)| Constraint::Fill(*width)))
+        .spacing(COLUMN_GAP)
+        .split(rect)
+}
+
+/// The height of the row for the given width, including one empty line as
+/// separator.
+pub fn row_height(row: &Row, context: &GroupContext, width: u16) -> u16 {
+    let rects = split_row(Rect::new(0, 0, width, 1), row);
+    let height = row
+        .iter()
+        .zip(rects.iter())
+        .map(|((interpretation, _), rect)| group_height(*interpretation, context, rect.width))
+        .max()
+        .unwrap_or(0);
+    height + 1
+}
+
+/// The height of the group for the given width, including the title.
+fn group_height(interpretation: Interpretation, context: &GroupContext, width: u16) -> u16 {
+    let height = match interpretation {
+        Interpretation::Bit64BigEndian => {
+            bit_editor_widget(context.editor, context.colors, width, false).line_count(width)
+        }
+        _ => {
+            let output_group =
+                get_output_group(context.user_input, interpretation, context.options);
+            let width = match interpretation {
+                Interpretation::Colors => colors_text_width(width),
+                _ => width,
+            };
+            context.renderer().widget(&output_group).line_count(width)
+        }
+    };
+    height as u16
+}
+
+/// The lines of the content that show the selected line of the focused
+/// group in `rect`, as start and end. For the first line, the title is
+/// included; for the bit editor, the whole group.
+pub fn selection_span(rect: Rect, context: &GroupContext) -> (u16, u16) {
+    let Some((interpretation, line)) = context.selected else {
+        return (rect.y, rect.y);
+    };
+    if interpretation == Interpretation::Bit64BigEndian {
+        return (
+            rect.y,
+            rect.y + group_height(interpretation, context, rect.width),
+        );
+    }
+    let width = match interpretation {
+        Interpretation::Colors => colors_text_width(rect.width),
+        _ => rect.width,
+    };
+    // lines above the selected line might wrap
+    let output_group = get_output_group(context.user_input, interpretation, context.options);
+    let line_height = |(key, value): (String, String)| {
+        Paragraph::new(format!("{key}: {value}"))
+            .wrap(Wrap { trim: false })
+            .line_count(width) as u16
+    };
+    let offset = output_group
+        .iter(context.format)
+        .take(line)
+        .map(line_height)
+        .sum::<u16>();
+    let height = output_group
+        .iter(context.format)
+        .nth(line)
+        .map_or(1, line_height);
+    let start = rect.y + 1 + offset;
+    if line == 0 {
+        (rect.y, start + height)
+    } else {
+        (start, start + height)
+    }
+}
+
+pub fn draw_row(buf: &mut Buffer, rect: Rect, row: &Row, context: &GroupContext) {
+    let rects = split_row(rect, row);
+    for ((interpretation, _), rect) in row.iter().zip(rects.iter()) {
+        draw_group(buf, *rect, *interpretation, context);
+    }
+}
+
+fn draw_group(
+    buf: &mut Buffer,
+    rect: Rect,
+    interpretation: Interpretation,
+    context: &GroupContext,
+) {
+    match interpretation {
+        Interpretation::Bit64BigEndian => draw_bit_editor(
+            buf,
+            rect,
+            context.editor,
+            context.colors,
+            context.selected_line(interpretation).is_some(),
+        ),
+        Interpretation::Colors => draw_colors(
+            buf,
+            rect,
+            context.user_input,
+            context.options,
+            &context.renderer(),
+        ),
+        _ => {
+            let output_group =
+                get_output_group(context.user_input, interpretation, context.options);
+            context.renderer().widget(&output_group).render(rect, buf)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_rows() {
+        use Interpretation::*;
+        let groups = [
+            (NumeralSystems, 40),
+            (IEEE754, 10),
+            (Bytes, 30),
+            (Bit64BigEndian, 80),
+            (Ibibytes, 30),
+            (Network, 50),
+            (Varints, 50),
+        ];
+        let interpretations = |rows: Vec<Row>| {
+            rows.into_iter()
+                .map(|row| row.into_iter().map(|(i, _)| i).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                vec![NumeralSystems, IEEE754],
+                vec![Bytes],
+                vec![Bit64BigEndian],
+                vec![Ibibytes],
+                vec![Network],
+                vec![Varints],
+            ],
+            interpretations(pack_rows(&groups, 60))
+        );
+        assert_eq!(
+            vec![
+                vec![NumeralSystems, IEEE754, Bytes],
+                vec![Bit64BigEndian],
+                vec![Ibibytes, Network, Varints],
+            ],
+            interpretations(pack_rows(&groups, 200))
+        );
+        // narrow terminals stack all groups
+        assert_eq!(7, pack_rows(&groups, 30).len());
+        assert!(pack_rows(&[], 80).is_empty());
+    }
+}
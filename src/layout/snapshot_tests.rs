@@ -0,0 +1,211 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Snapshot tests of the interactive view. Each test renders an [`App`] into
+//! a [`TestBackend`] and compares the text of the screen with the snapshot
+//! in `src/layout/snapshots/`.
+//!
+//! On a mismatch, the new screen is written next to the snapshot as
+//! `<name>.txt.new`. Review the difference and either fix the code or
+//! accept all new screens with `UPDATE_SNAPSHOTS=1 cargo test`.
+
+use super::*;
+use crate::config::Theme;
+use crossterm::event::KeyEvent;
+use ratatui::backend::TestBackend;
+use std::fs;
+use std::path::PathBuf;
+
+/// Renders the input with the keys applied, in a terminal of the given size.
+fn render(input: &str, keys: &str, width: u16, height: u16, history: &History) -> String {
+    let parsed = parse_input(input, &InputOptions::default()).unwrap();
+    let options = OutputOptions::default();
+    let input_options = InputOptions::default();
+    let config = Config::default();
+    // the monochrome theme doesn't depend on `COLORTERM`
+    let colors = Theme::Monochrome.colors();
+    let mut app = App::new(&parsed, &options, &input_options, history, &config, &colors);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    // the first draw stores the height for PgUp/PgDn
+    terminal.draw(|f| app.draw(f)).unwrap();
+    for key in parse_keys(keys) {
+        if let Effect::Copy(text) = app.handle_key(key) {
+            app.copied(&text, Ok(()));
+        }
+    }
+    terminal.draw(|f| app.draw(f)).unwrap();
+    screen_text(terminal.backend().buffer())
+}
+
+/// Parses a whitespace separated key script, such as `Tab j y`. Names of
+/// special keys start with an uppercase letter; everything else is a
+/// character key.
+fn parse_keys(keys: &str) -> Vec<KeyEvent> {
+    keys.split_whitespace()
+        .map(|key| {
+            let code = match key {
+                "Tab" => KeyCode::Tab,
+                "BackTab" => KeyCode::BackTab,
+                "Left" => KeyCode::Left,
+                "Right" => KeyCode::Right,
+                "Up" => KeyCode::Up,
+                "Down" => KeyCode::Down,
+                "PageUp" => KeyCode::PageUp,
+                "PageDown" => KeyCode::PageDown,
+                "Enter" => KeyCode::Enter,
+                "Esc" => KeyCode::Esc,
+                "Space" => KeyCode::Char(' '),
+                key if key.chars().count() == 1 => KeyCode::Char(key.chars().next().unwrap()),
+                key => panic!("unknown key `{key}` in key script"),
+            };
+            KeyEvent::from(code)
+        })
+        .collect()
+}
+
+/// The text of the screen, with trailing spaces removed. The version in the
+/// title is replaced, so that releases don't change the snapshots.
+fn screen_text(buffer: &Buffer) -> String {
+    let width = usize::from(buffer.area.width);
+    let text = buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let line = row.iter().map(|cell| cell.symbol()).collect::<String>();
+            format!("{}\n", line.trim_end())
+        })
+        .collect::<String>();
+    text.replace(env!("CARGO_PKG_VERSION"), "<version>")
+}
+
+/// Compares the screen with the snapshot `name`.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/layout/snapshots")
+        .join(format!("{name}.txt"));
+    let new_path = path.with_extension("txt.new");
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        let _ = fs::remove_file(&new_path);
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    if expected != actual {
+        fs::write(&new_path, actual).unwrap();
+        panic!(
+            "the screen differs from the snapshot {}\n\
+             review it with `diff {} {}`, and accept all new screens with \
+             `UPDATE_SNAPSHOTS=1 cargo test`",
+            path.display(),
+            path.display(),
+            new_path.display(),
+        );
+    }
+    let _ = fs::remove_file(&new_path);
+}
+
+#[test]
+fn test_float_wide() {
+    let screen = render("0x40a00000", "", 120, 50, &History::default());
+    assert_snapshot("float_120x50", &screen);
+}
+
+#[test]
+fn test_float_medium() {
+    let screen = render("0x40a00000", "", 80, 40, &History::default());
+    assert_snapshot("float_80x40", &screen);
+}
+
+#[test]
+fn test_float_narrow() {
+    let screen = render("0x40a00000", "", 50, 30, &History::default());
+    assert_snapshot("float_50x30", &screen);
+}
+
+#[test]
+fn test_ipv6() {
+    let screen = render("fe80::1", "", 120, 50, &History::default());
+    assert_snapshot("ipv6_120x50", &screen);
+}
+
+#[test]
+fn test_color() {
+    let screen = render("#ff8800", "", 120, 50, &History::default());
+    assert_snapshot("color_120x50", &screen);
+}
+
+#[test]
+fn test_too_small() {
+    let screen = render("42", "", 40, 9, &History::default());
+    assert_snapshot("too_small_40x9", &screen);
+}
+
+#[test]
+fn test_help() {
+    let screen = render("42", "?", 120, 50, &History::default());
+    assert_snapshot("help_120x50", &screen);
+}
+
+#[test]
+fn test_bit_editor() {
+    let screen = render(
+        "0x40a00000",
+        "Left Left Space",
+        120,
+        50,
+        &History::default(),
+    );
+    assert_snapshot("bit_editor_120x50", &screen);
+}
+
+#[test]
+fn test_copy() {
+    let screen = render("0xff", "j y", 120, 50, &History::default());
+    assert_snapshot("copy_120x50", &screen);
+}
+
+#[test]
+fn test_history() {
+    // a missing file results in an empty history, and it's never saved
+    let path = std::env::temp_dir().join("wambo-snapshot-tests-history");
+    let mut history = History::load(Some(path)).unwrap();
+    for input in ["0x10", "1mib", "fe80::1"] {
+        let parsed = parse_input(input, &InputOptions::default()).unwrap();
+        history.add(input, &parsed);
+    }
+    let screen = render("42", "H", 120, 50, &history);
+    assert_snapshot("history_120x50", &screen);
+}
+
+#[test]
+fn test_scroll() {
+    let screen = render("0x40a00000", "PageDown", 80, 20, &History::default());
+    assert_snapshot("scroll_80x20", &screen);
+}
+
+#[test]
+fn test_precision() {
+    let screen = render("0x3fb999999999999a", "p p", 120, 50, &History::default());
+    assert_snapshot("precision_120x50", &screen);
+}
@@ -0,0 +1,129 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Drawing of the color group, with a swatch for each color.
+
+use super::*;
+use crate::ParsedUserInput;
+use crate::print::{Interpretation, OutputOptions, Rgba, color_swatches, get_output_group};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+/// Width of a color swatch in cells.
+const SWATCH_WIDTH: u16 = 4;
+
+/// Draws the color group with a swatch next to each line.
+pub fn draw_colors(
+    buf: &mut Buffer,
+    parent_rect: Rect,
+    user_input: &ParsedUserInput,
+    options: &OutputOptions,
+    renderer: &TuiRenderer,
+) {
+    let layout_colors = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Length(SWATCH_WIDTH),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(parent_rect);
+
+    let output_group = get_output_group(user_input, Interpretation::Colors, options);
+    draw_swatches(
+        buf,
+        layout_colors[0],
+        user_input,
+        &output_group,
+        layout_colors[2].width,
+        renderer,
+    );
+
+    let paragraph = renderer.widget(&output_group);
+    paragraph.render(layout_colors[2], buf)
+}
+
+/// The width that is left for the text of the color group next to the
+/// swatches.
+pub const fn colors_text_width(width: u16) -> u16 {
+    width.saturating_sub(SWATCH_WIDTH + 1)
+}
+
+/// Draws one swatch next to each line of the color group. The first line
+/// is left empty, as it is next to the title of the group. Lines that wrap
+/// at `text_width` get a higher swatch.
+fn draw_swatches(
+    buf: &mut Buffer,
+    rect: Rect,
+    user_input: &ParsedUserInput,
+    output_group: &OutputGroup,
+    text_width: u16,
+    renderer: &TuiRenderer,
+) {
+    let true_color = !renderer.colors.monochrome && supports_true_color();
+    let mut text = vec![Line::default()];
+    for (color, (key, value)) in color_swatches(user_input)
+        .into_iter()
+        .zip(output_group.iter(renderer.format))
+    {
+        let height = Paragraph::new(format!("{key}: {value}"))
+            .wrap(Wrap { trim: false })
+            .line_count(text_width);
+        text.extend(std::iter::repeat_n(swatch(color, true_color), height));
+    }
+    Paragraph::new(text).render(rect, buf)
+}
+
+/// Creates a swatch for the color. Terminals without true color support,
+/// and the monochrome theme, get a plain-text shade that reflects the
+/// lightness of the color instead.
+fn swatch(color: Rgba, true_color: bool) -> Line<'static> {
+    let width = usize::from(SWATCH_WIDTH);
+    if true_color {
+        Line::from(Span::styled(
+            " ".repeat(width),
+            Style::default().bg(Color::Rgb(color.r, color.g, color.b)),
+        ))
+    } else {
+        let lightness = (u16::from(color.r) + u16::from(color.g) + u16::from(color.b)) / 3;
+        let shade = match lightness {
+            0..=31 => ' ',
+            32..=95 => '░',
+            96..=159 => '▒',
+            160..=223 => '▓',
+            _ => '█',
+        };
+        Line::from(shade.to_string().repeat(width))
+    }
+}
+
+/// Checks if the terminal announces true color support, as there is no
+/// reliable way to query it.
+fn supports_true_color() -> bool {
+    std::env::var("COLORTERM")
+        .is_ok_and(|value| value.eq_ignore_ascii_case("truecolor") || value == "24bit")
+}
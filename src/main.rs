@@ -33,46 +33,192 @@ SOFTWARE.
 #![deny(missing_debug_implementations)]
 #![deny(rustdoc::all)]
 
+mod cli;
+mod clipboard;
+mod config;
+mod history;
 mod layout;
 mod parse;
 mod print;
+mod render;
 
-use crate::parse::{ParsedUserInput, parse_input};
+use crate::cli::{CliArgs, CliError, Command, parse_args};
+use crate::config::Config;
+use crate::history::{History, MAX_ENTRIES};
+use crate::parse::{InputOptions, ParsedUserInput, parse_input};
+use crate::print::{Interpretation, OutputOptions, get_output_group};
+use crate::render::{Format, PlainRenderer, Renderer, ReportRow, render_report};
 use layout::*;
+use std::io;
+use std::path::Path;
 use std::process::exit;
 use std::sync::atomic::AtomicBool;
 
-/// Turned to true if SIGINT or SIGTERM are received. Supports a graceful shutdown.
+/// Turned to true if SIGINT, SIGTERM, or SIGHUP are received while the TUI
+/// is shown. Supports a graceful shutdown that restores the terminal.
 pub static SIGNAL_STOP: AtomicBool = AtomicBool::new(false);
 
 /// **Wambo** is a binary and so far no library.
 /// It's an all-in-one binary to convert decimal/bin/oct/hex + interpret data as i8-i64, u8-u64,
 /// and f32/f64.
 fn main() {
-    let parsed = validate_args_and_parse_input();
+    let cli_args = validate_args();
+    let mut config = load_config_or_exit(cli_args.config.as_deref());
+    if let Some(precision) = cli_args.precision {
+        config.precision = precision;
+    }
+    let input_options = InputOptions {
+        endianness: cli_args.endianness.unwrap_or(config.endianness),
+        width: cli_args.width.or(config.width),
+    };
+    let mut history = load_history(cli_args.no_history || !config.history);
+    let options = OutputOptions {
+        q_formats: cli_args.q_formats.clone(),
+        endianness: input_options.endianness,
+        width: input_options.width,
+    };
+    if let Some(format) = cli_args.export {
+        print_report(
+            format,
+            &cli_args.input,
+            &history,
+            &input_options,
+            &options,
+            &config,
+        );
+        return;
+    }
+
+    let input = history
+        .resolve_references(&cli_args.input)
+        .unwrap_or_else(|e| {
+            eprintln!("Illegal input: {}", e);
+            exit(-1);
+        });
+    let parsed = parse_input_or_exit(&input, &input_options);
+    history.add(&input, &parsed);
+    if let Err(e) = history.save() {
+        let path = history.path().unwrap_or_else(|| Path::new(""));
+        eprintln!(
+            "Warning: Can't write the history file {}: {}",
+            path.display(),
+            e
+        );
+    }
+
+    if cli_args.literals {
+        let output_group = get_output_group(&parsed, Interpretation::Literals, &options);
+        let renderer = PlainRenderer {
+            options: config.format_options(),
+        };
+        print!("{}", renderer.render(&[output_group]));
+        return;
+    }
+
+    let groups = config
+        .groups
+        .iter()
+        .map(|interpretation| get_output_group(&parsed, *interpretation, &options))
+        .collect::<Vec<_>>();
+    if let Some(text) = cli_args
+        .format
+        .render_text(&groups, config.format_options())
+    {
+        print!("{}", text);
+        return;
+    }
 
+    let colors = config.colors(cli_args.color);
     let mut tui = tui_prepare().unwrap();
-    run_tui(&mut tui, &parsed).unwrap();
+    let result = run_tui(
+        &mut tui,
+        &parsed,
+        &options,
+        &input_options,
+        &history,
+        &config,
+        &colors,
+    );
     tui_cleanup(tui).unwrap();
+    if let Err(e) = result {
+        eprintln!("Error in the interactive view: {}", e);
+        exit(-1);
+    }
 }
 
-/// Validates the user input and parses it. Terminates the program, if the args are invalid.
-fn validate_args_and_parse_input() -> ParsedUserInput {
-    let args = std::env::args().collect::<Vec<String>>();
-    if args.len() == 1 {
-        eprintln!(
-            "Please provide an integer input in decimal, hex (0x), octal (0o), or bin (0b) format!"
-        );
-        eprintln!("Enter -h for help.");
+/// Validates the command line arguments. Terminates the program, if the args are invalid.
+fn validate_args() -> CliArgs {
+    match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(cli_args)) => cli_args,
+        Ok(Command::Help) => {
+            show_help();
+            exit(0);
+        }
+        Ok(Command::History { config, no_history }) => {
+            let config = load_config_or_exit(config.as_deref());
+            print_history(no_history || !config.history);
+            exit(0);
+        }
+        Ok(Command::PrintConfig(path)) => {
+            print!("{}", load_config_or_exit(path.as_deref()));
+            exit(0);
+        }
+        Err(CliError::MissingInput) => {
+            eprintln!(
+                "Please provide an integer input in decimal, hex (0x), octal (0o), or bin (0b) format!"
+            );
+            eprintln!("Enter -h for help.");
+            exit(-1);
+        }
+        Err(e) => {
+            eprintln!("Illegal arguments: {}", e);
+            eprintln!("Enter -h for help.");
+            exit(-1);
+        }
+    }
+}
+
+/// Loads the config file from `path` or the default location. Terminates
+/// the program, if the config file is invalid.
+fn load_config_or_exit(path: Option<&Path>) -> Config {
+    Config::load(path).unwrap_or_else(|e| {
+        let path = path.map(Path::to_path_buf).or_else(Config::default_path);
+        let path = path.unwrap_or_default();
+        eprintln!("Illegal config {}: {}", path.display(), e);
         exit(-1);
+    })
+}
+
+/// Loads the history from the default location. Problems with the history
+/// file are not fatal; wambo continues without history then.
+fn load_history(disabled: bool) -> History {
+    let path = if disabled {
+        None
+    } else {
+        History::default_path()
+    };
+    History::load(path).unwrap_or_else(|e| {
+        eprintln!("Warning: Can't read the history file: {}", e);
+        History::default()
+    })
+}
+
+/// Prints the history with the numbers that can be used as references,
+/// such as `$3`.
+fn print_history(disabled: bool) {
+    let history = load_history(disabled);
+    if history.path().is_none() {
+        println!("The history is disabled.");
+        return;
     }
-    let input = &args[1];
-    if input == "-h" || input == "--help" {
-        show_help();
-        exit(0);
+    for (i, entry) in history.entries().iter().enumerate() {
+        println!("{:>5}  {}  ({})", i + 1, entry.input(), entry.value());
     }
+}
 
-    let parsed = parse_input(input);
+/// Parses the user input. Terminates the program, if the input is invalid.
+fn parse_input_or_exit(input: &str, options: &InputOptions) -> ParsedUserInput {
+    let parsed = parse_input(input, options);
     match parsed {
         Ok(parsed) => parsed,
         Err(e) => {
@@ -82,6 +228,57 @@ fn validate_args_and_parse_input() -> ParsedUserInput {
     }
 }
 
+/// Prints a report with one row for the input or, if the input is `-`, for
+/// each non-empty line of stdin. Exported inputs are not added to the
+/// history. Terminates the program, if an input is invalid.
+fn print_report(
+    format: Format,
+    input: &str,
+    history: &History,
+    input_options: &InputOptions,
+    options: &OutputOptions,
+    config: &Config,
+) {
+    let inputs = if input == "-" {
+        io::stdin()
+            .lines()
+            .map(|line| {
+                line.unwrap_or_else(|e| {
+                    eprintln!("Can't read the inputs from stdin: {}", e);
+                    exit(-1);
+                })
+            })
+            .map(|line| line.trim().to_owned())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+    } else {
+        vec![input.to_owned()]
+    };
+    let rows = inputs
+        .into_iter()
+        .map(|input| {
+            let parsed = history
+                .resolve_references(&input)
+                .map_err(|e| e.to_string())
+                .and_then(|resolved| {
+                    parse_input(&resolved, input_options).map_err(|e| e.to_string())
+                })
+                .unwrap_or_else(|e| {
+                    eprintln!("Illegal input `{}`: {}", input, e);
+                    exit(-1);
+                });
+            let groups = config
+                .groups
+                .iter()
+                .map(|interpretation| get_output_group(&parsed, *interpretation, options))
+                .collect();
+            ReportRow { input, groups }
+        })
+        .collect::<Vec<_>>();
+    let report = render_report(format, &rows, &config.format_options());
+    print!("{}", report.unwrap_or_default());
+}
+
 fn show_help() {
     println!("Wambo - Decimal, Hex, Bin number + byte converter");
     println!("Wambo can easily show you information about a value in decimal, hex, binary and");
@@ -89,7 +286,8 @@ fn show_help() {
     println!();
     println!("Usage:");
     println!("  Input value can be a number in binary, octal, decimal or hexadecimal.");
-    println!("  Negative numbers are supported. Only integers, no decimals.");
+    println!("  Negative numbers are supported. Real numbers, such as 0.5, need a fixed-point");
+    println!("  format (see below).");
     println!("    $ wambo 42");
     println!("    $ wambo 0b10001111");
     println!("    $ wambo 0xdeadbeef");
@@ -107,4 +305,111 @@ fn show_help() {
     println!("    $ wambo 1mib (Mibibyte)");
     println!("    Valid units are: k/kb, m/mb, g/gb, t/tb");
     println!("                     ki/kib, mi/mib, gi/gib, ti/tib");
+    println!();
+    println!("  Input values can be real numbers in a fixed-point format (Qm.n, ARM notation).");
+    println!("  The result are the raw bits of the number in that format.");
+    println!("    $ wambo 0.5q15     (Q1.15, 16 bit)");
+    println!("    $ wambo -1.25q16.16");
+    println!("    $ wambo 3.5uq8.8   (unsigned)");
+    println!();
+    println!("  Input values can be byte sequences: hex dumps, array literals, or escaped");
+    println!("  strings. The bytes are assembled with the byte order from --endian.");
+    println!("    $ wambo de ad be ef");
+    println!("    $ wambo \"[0x78, 0x56, 0x34, 0x12]\"");
+    println!("    $ wambo '\\x7fELF' --endian big");
+    println!();
+    println!("  Input values can be encoded variable-length integers: a format (uleb128,");
+    println!("  sleb128, varint, sint64, quic, utf8) followed by a byte sequence. Bytes after");
+    println!("  the end of the encoded value are ignored.");
+    println!("    $ wambo uleb128 e5 8e 26");
+    println!("    $ wambo quic \"[0x7b, 0xbd]\"");
+    println!();
+    println!("  Input values can be IPv4 or IPv6 addresses (in network byte order).");
+    println!("    $ wambo 192.168.1.1");
+    println!("    $ wambo fe80::1");
+    println!();
+    println!("  Input values can be Linux ioctl macros.");
+    println!("    $ wambo \"_IOR('T', 1, 4)\"");
+    println!("    $ wambo \"_IOW(0x54, 2, sizeof(int))\"");
+    println!();
+    println!("  Input values can be symbolic file modes or flag sets (open, mmap, signals).");
+    println!("    $ wambo u+rwx,g+rx");
+    println!("    $ wambo \"O_RDWR|O_CREAT\"");
+    println!("    $ wambo \"S_IFDIR|0755\"");
+    println!();
+    println!("  Input values can be CSS colors (0xRRGGBB, or 0xRRGGBBAA with alpha).");
+    println!("    $ wambo \"#ff8800\"");
+    println!("    $ wambo \"rgb(255, 136, 0)\"");
+    println!("    $ wambo \"rgba(255, 136, 0, 0.5)\"");
+    println!();
+    println!("  Input values can be sums and differences (with spaces around + and -) and");
+    println!("  can reference earlier results from the history: $_ is the previous result");
+    println!("  and $3 is entry 3 of --history.");
+    println!("    $ wambo 1mib - 0x10");
+    println!("    $ wambo '$_ + 0x10'");
+    println!("    $ wambo '$3 - $_'");
+    println!();
+    println!("Options:");
+    println!("  --q <FORMAT>      Show the value in an additional fixed-point format, such");
+    println!("                    as q15 or uq8.8. Without the q/uq prefix, such as 15 or");
+    println!("                    12.4, both the signed and the unsigned format are shown");
+    println!("                    (Q1.15 and UQ1.15). Repeatable.");
+    println!("  --endian <ORDER>  Byte order of byte sequences and byte-wise literals:");
+    println!("                    little/le (default) or big/be.");
+    println!("  --width <BITS>    Width of the value: 8, 16, 32, or 64. Missing bytes of");
+    println!("                    byte sequences are zero. Default: derived from the input.");
+    println!("  --literals        Print ready-to-paste Rust, C, and Python literals of the");
+    println!("                    value instead of showing the interactive view.");
+    println!("  --format <FORMAT> Print all groups (see `groups` in the config) instead of");
+    println!("                    showing the interactive view: plain, json, csv, markdown/md,");
+    println!("                    or html. Default: tui (the interactive view).");
+    println!("  --export <FORMAT> Print a report with one row per input: markdown/md (a table");
+    println!("                    per group), csv (a column per line), or html (a snippet with");
+    println!("                    its own style). With - as input, each line of stdin is one");
+    println!("                    input: $ printf '42\\n0xff\\n' | wambo - --export md");
+    println!("  --precision <P>   Fraction digits of floats and sizes: at most N digits, such");
+    println!("                    as 4 (default), exactly N digits (fixed:N), the shortest");
+    println!("                    representation that reads back as the same float");
+    println!("                    (shortest), all digits of the binary value (exact), or");
+    println!("                    hex-float notation as %a in C (hex).");
+    println!("  --history         Print the input history and exit.");
+    println!("  --no-history      Neither read nor write the history file.");
+    println!("  --config <PATH>   Use this config file instead of the default one.");
+    println!("  --color <WHEN>    Colors of the interactive view: auto (default; no colors if");
+    println!("                    $NO_COLOR is set), always, or never.");
+    println!("  --print-config    Print the effective config (defaults merged with the");
+    println!("                    config file) and exit.");
+    println!("  -h, --help        Show this help.");
+    println!("  --                End of the options: all following arguments are input.");
+    println!();
+    println!("History:");
+    println!(
+        "  The last {} inputs are stored in $XDG_STATE_HOME/wambo/history (default:",
+        MAX_ENTRIES
+    );
+    println!("  ~/.local/state/wambo/history). $WAMBO_HISTORY_FILE overrides the location and");
+    println!("  a non-empty $WAMBO_NO_HISTORY disables the history.");
+    println!();
+    println!("Config:");
+    println!("  Defaults are read from $XDG_CONFIG_HOME/wambo/config.toml (default:");
+    println!("  ~/.config/wambo/config.toml). Command line options take precedence.");
+    println!("    precision = 4                  # fraction digits of floats and sizes, or");
+    println!("                                   # \"fixed:8\", \"shortest\", \"exact\", \"hex\"");
+    println!("    groups = [\"numeral-systems\", \"bits\"]  # shown groups, in this order");
+    println!("    endianness = \"little\"");
+    println!("    width = 32");
+    println!("    digit_grouping = true          # 1_000_000, 0xdead_beef");
+    println!("    history = false");
+    println!("    theme = \"light\"                # auto, dark, light, high-contrast, monochrome");
+    println!("    literal_language = \"c\"         # copied literals: rust, c, python");
+    println!("    [colors]                       # title, text, background, accent, error");
+    println!("    title = \"cyan\"                # names, or \"#rrggbb\"");
+    println!("  The theme \"auto\" detects light terminals via $COLORFGBG. A theme in the");
+    println!("  config file takes precedence over $NO_COLOR.");
+    println!("  `wambo --print-config` prints all keys with their current values.");
+    println!();
+    println!("Keys in the interactive view:");
+    for (keys, description) in KEY_BINDINGS {
+        println!("  {:<18}{}", keys, description);
+    }
 }
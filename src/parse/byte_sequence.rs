@@ -0,0 +1,321 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for byte sequences, such as hex dumps and array literals.
+
+use crate::parse::endianness::Endianness;
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{InputOptions, NumeralSystem, ParsedUserInput};
+use std::fmt::{Display, Formatter};
+
+/// Describes how a byte sequence input was assembled into the value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct ByteSequenceInfo {
+    count: usize,
+    endianness: Endianness,
+    width: u32,
+}
+
+#[allow(dead_code)]
+impl ByteSequenceInfo {
+    /// Getter for `count`. The number of bytes in the input.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Getter for `endianness`.
+    pub const fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Getter for `width`. The width of the assembled value in bits.
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+}
+
+impl Display for ByteSequenceInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} byte{}, {}, {} bit",
+            self.count,
+            if self.count == 1 { "" } else { "s" },
+            self.endianness,
+            self.width
+        )
+    }
+}
+
+/// Parses byte sequence inputs. Returns `None` if the input is not a byte
+/// sequence. Supported are:
+/// - array literals: `[0x78, 0x56, 0x34, 0x12]` or `{0xef, 0xbe, 0xad, 0xde}`
+/// - escaped strings: `\x7fELF` or `b"\x7fELF"`
+/// - hex dumps: `de ad be ef`
+///
+/// The bytes are assembled according to [`InputOptions`].
+pub fn try_parse_input(
+    input: &str,
+    options: &InputOptions,
+) -> Option<Result<ParsedUserInput, ParseError>> {
+    let bytes = try_parse_bytes(input)?;
+    Some(bytes.and_then(|bytes| assemble(input, &bytes, options)))
+}
+
+/// Extracts the bytes of a byte sequence input. Returns `None` if the input
+/// is not a byte sequence.
+pub fn try_parse_bytes(input: &str) -> Option<Result<Vec<u8>, ParseError>> {
+    let input = input.trim();
+    let is_array = (input.starts_with('[') && input.ends_with(']'))
+        || (input.starts_with('{') && input.ends_with('}'));
+    if is_array {
+        return Some(parse_array(&input[1..input.len() - 1]));
+    }
+    if input.contains("\\x") {
+        return Some(parse_escaped_string(input));
+    }
+    let tokens = input.split_whitespace().collect::<Vec<_>>();
+    let is_hex_dump = tokens.len() > 1
+        && tokens.iter().all(|token| {
+            let digits = token.strip_prefix("0x").unwrap_or(token);
+            digits.len() == 2 && digits.chars().all(|c| c.is_ascii_hexdigit())
+        });
+    if is_hex_dump {
+        return Some(parse_hex_dump(&tokens));
+    }
+    None
+}
+
+/// Parses the elements of an array literal, without the brackets. Elements
+/// can be hexadecimal (`0x`), binary (`0b`), octal (`0o`), or decimal.
+fn parse_array(elements: &str) -> Result<Vec<u8>, ParseError> {
+    elements
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|element| !element.is_empty())
+        .map(|element| {
+            let normalized = element.to_lowercase();
+            let (digits, radix) = [("0x", 16), ("0b", 2), ("0o", 8)]
+                .iter()
+                .find_map(|(prefix, radix)| {
+                    normalized
+                        .strip_prefix(prefix)
+                        .map(|digits| (digits, *radix))
+                })
+                .unwrap_or((normalized.as_str(), 10));
+            u8::from_str_radix(digits, radix)
+                .map_err(|_| ParseError::InvalidFormat(format!("Invalid byte: {element}")))
+        })
+        .collect()
+}
+
+/// Parses a string with `\xHH` escapes, such as `\x7fELF`. Optionally, the
+/// string is enclosed in `"..."` or `b"..."`. Characters that are not escaped
+/// stand for their UTF-8 encoding.
+fn parse_escaped_string(string: &str) -> Result<Vec<u8>, ParseError> {
+    let string = string.strip_prefix('b').unwrap_or(string);
+    let string = string
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(string);
+
+    let mut bytes = Vec::new();
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        let byte = match chars.next() {
+            Some('x') => {
+                let digits = chars.by_ref().take(2).collect::<String>();
+                u8::from_str_radix(&digits, 16).map_err(|_| {
+                    ParseError::InvalidFormat(format!("Invalid escape sequence: \\x{digits}"))
+                })?
+            }
+            Some('0') => b'\0',
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('t') => b'\t',
+            Some('\\') => b'\\',
+            Some('"') => b'"',
+            other => {
+                return Err(ParseError::InvalidFormat(format!(
+                    "Invalid escape sequence: \\{}",
+                    other.map(String::from).unwrap_or_default()
+                )));
+            }
+        };
+        bytes.push(byte);
+    }
+    Ok(bytes)
+}
+
+/// Parses whitespace separated hex bytes, such as `de ad be ef`.
+fn parse_hex_dump(tokens: &[&str]) -> Result<Vec<u8>, ParseError> {
+    tokens
+        .iter()
+        .map(|token| {
+            u8::from_str_radix(token.strip_prefix("0x").unwrap_or(token), 16)
+                .map_err(|_| ParseError::InvalidFormat(format!("Invalid byte: {token}")))
+        })
+        .collect()
+}
+
+/// Assembles the bytes to a value with the byte order and width from the
+/// [`InputOptions`]. Without an explicit width, the width is given by the
+/// number of bytes. Sequences of up to 16 bytes are supported; values wider
+/// than 64 bit are stored in [`ParsedUserInput::wide_bits`]. Missing bytes
+/// are treated as zero.
+fn assemble(
+    input: &str,
+    bytes: &[u8],
+    options: &InputOptions,
+) -> Result<ParsedUserInput, ParseError> {
+    if bytes.is_empty() {
+        return Err(ParseError::InvalidFormat("Empty byte sequence".to_string()));
+    }
+    let max_bytes = options.width.map_or(16, |width| width as usize / 8);
+    if bytes.len() > max_bytes {
+        return Err(ParseError::OutOfRange(format!(
+            "{} bytes don't fit into {} bit",
+            bytes.len(),
+            max_bytes * 8
+        )));
+    }
+
+    let mut buf = [0; 16];
+    let value = match options.endianness {
+        Endianness::Little => {
+            buf[..bytes.len()].copy_from_slice(bytes);
+            u128::from_le_bytes(buf)
+        }
+        Endianness::Big => {
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            u128::from_be_bytes(buf)
+        }
+    };
+    let width = options.width.unwrap_or(if bytes.len() > 8 {
+        128
+    } else {
+        bytes.len() as u32 * 8
+    });
+    let info = ByteSequenceInfo {
+        count: bytes.len(),
+        endianness: options.endianness,
+        width,
+    };
+
+    let mut parsed = ParsedUserInput::from_value(
+        input.trim().to_owned(),
+        NumeralSystem::Hex,
+        Sign::Positive,
+        value as u64,
+    );
+    parsed.byte_sequence = Some(info);
+    if width > 64 {
+        parsed.wide_bits = Some(value);
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_try_parse_bytes() {
+        let expected = vec![0x78, 0x56, 0x34, 0x12];
+        assert_eq!(
+            expected,
+            try_parse_bytes("[0x78, 0x56, 0x34, 0x12]")
+                .unwrap()
+                .unwrap()
+        );
+        assert_eq!(
+            expected,
+            try_parse_bytes("{0x78,0x56,52,0b10010}").unwrap().unwrap()
+        );
+        assert_eq!(expected, try_parse_bytes("78 56 34 12").unwrap().unwrap());
+        assert_eq!(
+            expected,
+            try_parse_bytes("0x78 0x56 0x34 0x12").unwrap().unwrap()
+        );
+        assert_eq!(
+            vec![0x7f, b'E', b'L', b'F'],
+            try_parse_bytes("\\x7fELF").unwrap().unwrap()
+        );
+        assert_eq!(
+            vec![0x7f, b'E', b'L', b'F'],
+            try_parse_bytes("b\"\\x7fELF\"").unwrap().unwrap()
+        );
+        assert!(try_parse_bytes("[0x100]").unwrap().is_err());
+        assert!(try_parse_bytes("\\xzz").unwrap().is_err());
+        assert!(try_parse_bytes("0xdeadbeef").is_none());
+        assert!(try_parse_bytes("1 2").is_none());
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        let options = InputOptions::default();
+        let parsed = try_parse_input("[0x78, 0x56, 0x34, 0x12]", &options)
+            .unwrap()
+            .unwrap();
+        assert_eq!(0x1234_5678, parsed.value());
+        let info = parsed.byte_sequence().unwrap();
+        assert_eq!(4, info.count());
+        assert_eq!(32, info.width());
+
+        let options = InputOptions {
+            endianness: Endianness::Big,
+            width: Some(64),
+        };
+        let parsed = try_parse_input("de ad be ef", &options).unwrap().unwrap();
+        assert_eq!(0xdead_beef, parsed.value());
+        assert_eq!(64, parsed.byte_sequence().unwrap().width());
+
+        let options = InputOptions {
+            endianness: Endianness::Little,
+            width: Some(16),
+        };
+        assert!(try_parse_input("de ad be ef", &options).unwrap().is_err());
+        assert!(matches!(
+            try_parse_input("[]", &options).unwrap(),
+            Err(ParseError::InvalidFormat(msg)) if msg == "Empty byte sequence"
+        ));
+
+        let options = InputOptions {
+            endianness: Endianness::Big,
+            width: None,
+        };
+        let parsed = try_parse_input("fe 80 00 00 00 00 00 00 00 00 00 00 00 00 00 01", &options)
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, parsed.value());
+        assert_eq!(Some(0xfe80 << 112 | 1), parsed.wide_bits());
+        assert_eq!(128, parsed.byte_sequence().unwrap().width());
+    }
+}
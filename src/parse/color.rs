@@ -0,0 +1,129 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for CSS colors, such as `#ff8800` or `rgb(255, 136, 0)`.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+
+/// Parses CSS colors into `0xRRGGBB`, or into `0xRRGGBBAA` if they have an
+/// alpha channel. Supported are `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`,
+/// `rgb(r, g, b)`, and `rgba(r, g, b, a)` where `a` is in `0.0..=1.0`.
+/// Returns `None` if the input is not a CSS color.
+pub fn try_parse_input(normalized_input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    let value = if let Some(hex) = normalized_input.strip_prefix('#') {
+        parse_hex_color(hex)
+    } else if let Some(args) = normalized_input
+        .strip_prefix("rgba(")
+        .or_else(|| normalized_input.strip_prefix("rgb("))
+    {
+        parse_rgb_function(args)
+    } else {
+        return None;
+    };
+
+    let parsed = value
+        .ok_or_else(|| ParseError::InvalidFormat(format!("Invalid CSS color: {normalized_input}")))
+        .map(|value| {
+            ParsedUserInput::from_value(
+                normalized_input.to_owned(),
+                NumeralSystem::Hex,
+                Sign::Positive,
+                value,
+            )
+        });
+    Some(parsed)
+}
+
+fn parse_hex_color(hex: &str) -> Option<u64> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        // short forms: each digit is duplicated, `#f80` is `#ff8800`
+        3 | 4 => hex.chars().try_fold(0, |value, c| {
+            let digit = u64::from(c.to_digit(16)?);
+            Some(value << 8 | digit << 4 | digit)
+        }),
+        6 | 8 => u64::from_str_radix(hex, 16).ok(),
+        _ => None,
+    }
+}
+
+/// Parses the arguments of `rgb()` or `rgba()`, including the closing
+/// parenthesis.
+fn parse_rgb_function(args: &str) -> Option<u64> {
+    let args = args
+        .strip_suffix(')')?
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    let (rgb, alpha) = match args.as_slice() {
+        [r, g, b] => ([r, g, b], None),
+        [r, g, b, a] => ([r, g, b], Some(a)),
+        _ => return None,
+    };
+    let rgb = rgb.iter().try_fold(0, |value, component| {
+        Some(value << 8 | u64::from(component.parse::<u8>().ok()?))
+    })?;
+    match alpha {
+        None => Some(rgb),
+        Some(alpha) => {
+            let alpha = alpha
+                .parse::<f64>()
+                .ok()
+                .filter(|a| (0.0..=1.0).contains(a))?;
+            Some(rgb << 8 | (alpha * 255.0).round() as u64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(input: &str) -> Option<u64> {
+        try_parse_input(input)
+            .unwrap()
+            .ok()
+            .map(|parsed| parsed.value())
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        assert_eq!(Some(0xff8800), parse("#ff8800"));
+        assert_eq!(Some(0xff8800), parse("#f80"));
+        assert_eq!(Some(0xff8800cc), parse("#ff8800cc"));
+        assert_eq!(Some(0xff8800cc), parse("#f80c"));
+        assert_eq!(Some(0xff8800), parse("rgb(255, 136, 0)"));
+        assert_eq!(Some(0xff880080), parse("rgba(255,136,0,0.5)"));
+        assert_eq!(None, parse("#ff880"));
+        assert_eq!(None, parse("#gg8800"));
+        assert_eq!(None, parse("rgb(256, 0, 0)"));
+        assert_eq!(None, parse("rgba(0, 0, 0, 2)"));
+        assert!(try_parse_input("0xff8800").is_none());
+    }
+}
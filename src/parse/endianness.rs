@@ -0,0 +1,69 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for the byte order.
+
+use crate::parse::error::ParseError;
+use derive_more::Display;
+
+/// Byte order of multi-byte values.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Display)]
+pub enum Endianness {
+    /// Least significant byte first.
+    #[default]
+    #[display("Little Endian")]
+    Little,
+    /// Most significant byte first.
+    #[display("Big Endian")]
+    Big,
+}
+
+impl Endianness {
+    /// Parses the [`Endianness`] from a normalized string, such as `le` or `big`.
+    pub fn from_input(part_str: &str) -> Result<Self, ParseError> {
+        match part_str {
+            "le" | "little" => Ok(Self::Little),
+            "be" | "big" => Ok(Self::Big),
+            _ => Err(ParseError::InvalidEndianness(part_str.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_endianness() {
+        assert_eq!(Endianness::Little, Endianness::from_input("le").unwrap());
+        assert_eq!(
+            Endianness::Little,
+            Endianness::from_input("little").unwrap()
+        );
+        assert_eq!(Endianness::Big, Endianness::from_input("be").unwrap());
+        assert_eq!(Endianness::Big, Endianness::from_input("big").unwrap());
+        assert!(Endianness::from_input("middle").is_err());
+    }
+}
@@ -35,6 +35,15 @@ pub enum ParseError {
     InvalidFormat(String),
     #[display("Invalid unit: {_0}")]
     InvalidUnit(String),
+    /// Tells that a fixed-point format such as `Q15` or `UQ8.8` is invalid.
+    #[display("Invalid Q format: {_0}")]
+    InvalidQFormat(String),
+    /// Tells that the byte order is neither little nor big endian.
+    #[display("Invalid endianness: {_0}")]
+    InvalidEndianness(String),
+    /// Tells that the value can't be represented in the requested format.
+    #[display("Value out of range: {_0}")]
+    OutOfRange(String),
 }
 
 // IDE may show that "Display" is not implemented, but it gets implemented
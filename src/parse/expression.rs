@@ -0,0 +1,143 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for simple expressions, such as `0x40a00000 + 0x10`.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{InputOptions, ParsedUserInput, parse_input};
+
+/// Parses sums and differences of inputs, such as `1mib - 0x10`. Operators
+/// must be surrounded by whitespace, so that they can't be mistaken for a
+/// sign or a symbolic file mode, such as `u+rwx`. The operands can be any
+/// input. Returns `None` if the input has no operator.
+pub fn try_parse_input(
+    input: &str,
+    options: &InputOptions,
+) -> Option<Result<ParsedUserInput, ParseError>> {
+    let tokens = input.split_whitespace().collect::<Vec<_>>();
+    if !tokens.iter().any(|token| is_operator(token)) {
+        return None;
+    }
+    Some(evaluate(&tokens, options))
+}
+
+fn is_operator(token: &str) -> bool {
+    token == "+" || token == "-"
+}
+
+fn evaluate(tokens: &[&str], options: &InputOptions) -> Result<ParsedUserInput, ParseError> {
+    let mut operands = tokens.split(|token| is_operator(token));
+    let operators = tokens.iter().filter(|token| is_operator(token));
+
+    let first = parse_operand(operands.next().unwrap_or_default(), options)?;
+    let numeral_system = first.numeral_system();
+    let mut result = signed_value(&first)?;
+    for (operator, operand) in operators.zip(operands) {
+        let operand = signed_value(&parse_operand(operand, options)?)?;
+        result = if *operator == "+" {
+            result + operand
+        } else {
+            result - operand
+        };
+    }
+
+    let normalized_input = tokens.join(" ");
+    let magnitude = u64::try_from(result.unsigned_abs()).map_err(|_| {
+        ParseError::OutOfRange(format!(
+            "Result of {normalized_input} doesn't fit into 64 bit"
+        ))
+    })?;
+    let sign = if result < 0 {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    Ok(ParsedUserInput::from_value(
+        normalized_input,
+        numeral_system,
+        sign,
+        magnitude,
+    ))
+}
+
+fn parse_operand(tokens: &[&str], options: &InputOptions) -> Result<ParsedUserInput, ParseError> {
+    if tokens.is_empty() {
+        return Err(ParseError::InvalidFormat(
+            "Missing operand in expression".to_string(),
+        ));
+    }
+    parse_input(&tokens.join(" "), options)
+}
+
+/// The value of an operand. Operands that don't fit into 64 bit, such as
+/// most IPv6 addresses, are errors instead of being truncated.
+fn signed_value(parsed: &ParsedUserInput) -> Result<i128, ParseError> {
+    if parsed.wide_bits().is_some_and(|bits| bits >> 64 != 0) {
+        return Err(ParseError::OutOfRange(format!(
+            "{} exceeds 64 bits and can't be used in an expression",
+            parsed.normalized_input()
+        )));
+    }
+    let value = i128::from(parsed.value());
+    Ok(if parsed.sign().is_neg() {
+        -value
+    } else {
+        value
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(input: &str) -> Result<ParsedUserInput, ParseError> {
+        try_parse_input(input, &InputOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        assert_eq!(0x40a0_0010, parse("0x40a00000 + 0x10").unwrap().value());
+        assert_eq!(1_048_560, parse("1mib - 0x10").unwrap().value());
+
+        let parsed = parse("5 - -0x10 - 30").unwrap();
+        assert!(parsed.sign().is_neg());
+        assert_eq!(9, parsed.value());
+
+        assert!(parse("0xffffffffffffffff + 1").is_err());
+        assert!(parse("5 +").is_err());
+        assert_eq!(2, parse("::1 + 1").unwrap().value());
+        assert!(matches!(
+            parse("fe80::1 + 1"),
+            Err(ParseError::OutOfRange(msg)) if msg.starts_with("fe80::1 exceeds 64 bits")
+        ));
+        assert!(matches!(
+            parse("1 - 00 11 22 33 44 55 66 77 88 99 aa bb cc dd ee ff"),
+            Err(ParseError::OutOfRange(_))
+        ));
+        assert!(try_parse_input("u+rwx", &InputOptions::default()).is_none());
+        assert!(try_parse_input("-5", &InputOptions::default()).is_none());
+    }
+}
@@ -0,0 +1,130 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for symbolic file modes, as used by `chmod(1)`.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+use regex::Regex;
+
+/// Regex for symbolic file modes, such as `u+rwx,g+rx` or `a=r,u+w`.
+/// Expects normalized input.
+pub const SYMBOLIC_MODE_REGEX: &str = "^[ugoa]*([-+=][rwxst]+)+(,[ugoa]*([-+=][rwxst]+)+)*$";
+
+/// Parses symbolic file modes, such as `u+rwx,g+rx`, into the mode bits.
+/// The clauses are applied from left to right to an initial mode of `0`.
+/// Like `chmod(1)` without a umask, a missing "who" means "all". Returns
+/// `None` if the input is not a symbolic mode.
+pub fn try_parse_input(normalized_input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    if !Regex::new(SYMBOLIC_MODE_REGEX)
+        .unwrap()
+        .is_match(normalized_input)
+    {
+        return None;
+    }
+
+    let mut mode = 0_u64;
+    for clause in normalized_input.split(',') {
+        let who_end = clause.find(['-', '+', '=']).unwrap();
+        let who = match &clause[..who_end] {
+            "" => "ugo",
+            who => who,
+        };
+        let who_mask = who_mask(who);
+
+        let mut rest = &clause[who_end..];
+        while let Some(op) = rest.chars().next() {
+            let perms_end = rest[1..]
+                .find(['-', '+', '='])
+                .map_or(rest.len(), |i| i + 1);
+            let bits = perm_bits(&rest[1..perms_end], who) & who_mask;
+            match op {
+                '+' => mode |= bits,
+                '-' => mode &= !bits,
+                _ => mode = (mode & !who_mask) | bits,
+            }
+            rest = &rest[perms_end..];
+        }
+    }
+
+    Some(Ok(ParsedUserInput::from_value(
+        normalized_input.to_owned(),
+        NumeralSystem::Octal,
+        Sign::Positive,
+        mode,
+    )))
+}
+
+/// All mode bits that belong to the given users, including the special
+/// bits (setuid, setgid, sticky).
+fn who_mask(who: &str) -> u64 {
+    who.chars()
+        .map(|c| match c {
+            'u' => 0o4700,
+            'g' => 0o2070,
+            'o' => 0o1007,
+            _ => 0o7777,
+        })
+        .fold(0, |mask, bits| mask | bits)
+}
+
+/// Mode bits of permission characters such as `rwx` for all users. The
+/// result must be masked with [`who_mask`].
+fn perm_bits(perms: &str, who: &str) -> u64 {
+    perms
+        .chars()
+        .map(|c| match c {
+            'r' => 0o444,
+            'w' => 0o222,
+            'x' => 0o111,
+            // sticky bit only makes sense for "others" or "all"
+            't' if who.contains(['o', 'a']) => 0o1000,
+            't' => 0,
+            _ => 0o6000,
+        })
+        .fold(0, |bits, bit| bits | bit)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(input: &str) -> u64 {
+        try_parse_input(input).unwrap().unwrap().value()
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        assert_eq!(0o750, parse("u+rwx,g+rx"));
+        assert_eq!(0o755, parse("a+rx,u+w"));
+        assert_eq!(0o644, parse("=r,u+w"));
+        assert_eq!(0o4755, parse("u=rwxs,go=rx"));
+        assert_eq!(0o1777, parse("a+rwxt"));
+        assert_eq!(0o600, parse("u+rwx-x"));
+        assert!(try_parse_input("-7").is_none());
+        assert!(try_parse_input("0x755").is_none());
+    }
+}
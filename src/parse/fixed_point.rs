@@ -0,0 +1,217 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for fixed-point (Q-format) numbers.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+
+/// Regex for a Q-format specification in ARM notation, such as `q15`, `q16.16`,
+/// or `uq8.8`. Expects normalized input.
+pub const Q_FORMAT_REGEX: &str = "^(?P<unsigned>u)?q(?P<m>[0-9]{1,2})(\\.(?P<n>[0-9]{1,2}))?$";
+
+/// Regex for a real number that should be converted into the raw bits of a
+/// fixed-point format, such as `0.5q15` or `-1.25q16.16`. Expects normalized input.
+pub const FIXED_POINT_INPUT_REGEX: &str =
+    "^(?P<sign>-)?(?P<real>[0-9]+(\\.[0-9]+)?)(?P<format>u?q[0-9]{1,2}(\\.[0-9]{1,2})?)$";
+
+/// A fixed-point number format in ARM notation: `Qm.n` (signed) or `UQm.n`
+/// (unsigned). `m` is the number of integer bits, including the sign bit for
+/// signed formats, and `n` the number of fractional bits. The total width is
+/// therefore `m + n`. `Qn` is short for `Q1.n` and `UQn` for `UQ0.n`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct QFormat {
+    signed: bool,
+    int_bits: u8,
+    frac_bits: u8,
+}
+
+impl QFormat {
+    /// Formats that are common in DSP and embedded code.
+    pub const COMMON: [Self; 7] = [
+        Self::new(true, 1, 7),
+        Self::new(true, 1, 15),
+        Self::new(true, 1, 31),
+        Self::new(true, 16, 16),
+        Self::new(false, 8, 8),
+        Self::new(false, 16, 16),
+        Self::new(false, 32, 32),
+    ];
+
+    const fn new(signed: bool, int_bits: u8, frac_bits: u8) -> Self {
+        Self {
+            signed,
+            int_bits,
+            frac_bits,
+        }
+    }
+
+    /// Parses a [`QFormat`] from a normalized string such as `q15` or `uq8.8`.
+    pub fn from_input(part_str: &str) -> Result<Self, ParseError> {
+        let regex = Regex::new(Q_FORMAT_REGEX).unwrap();
+        let captures = regex
+            .captures(part_str)
+            .ok_or_else(|| ParseError::InvalidQFormat(part_str.to_owned()))?;
+
+        let signed = captures.name("unsigned").is_none();
+        let m = captures["m"].parse::<u8>().unwrap();
+        // `Qn` is short for `Q1.n` and `UQn` for `UQ0.n`
+        let (int_bits, frac_bits) = captures.name("n").map_or_else(
+            || (u8::from(signed), m),
+            |n| (m, n.as_str().parse::<u8>().unwrap()),
+        );
+
+        let width = u32::from(int_bits) + u32::from(frac_bits);
+        if width == 0 || width > 64 || (signed && int_bits == 0) {
+            return Err(ParseError::InvalidQFormat(part_str.to_owned()));
+        }
+        Ok(Self::new(signed, int_bits, frac_bits))
+    }
+
+    /// Returns the same format with the other signedness, i.e., `Q12.4` for
+    /// `UQ12.4` and vice versa.
+    pub const fn with_signedness(self, signed: bool) -> Self {
+        Self::new(signed, self.int_bits, self.frac_bits)
+    }
+
+    /// Getter for `signed`.
+    pub const fn is_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Getter for `frac_bits`.
+    pub const fn frac_bits(&self) -> u32 {
+        self.frac_bits as u32
+    }
+
+    /// Total width of the format in bits.
+    pub const fn width(&self) -> u32 {
+        self.int_bits as u32 + self.frac_bits as u32
+    }
+
+    /// Converts a real number into the raw integer bits of this format. The
+    /// value is rounded to the nearest representable value.
+    pub fn encode(&self, real: f64) -> Result<i128, ParseError> {
+        let raw = (real * 2_f64.powi(self.frac_bits() as i32)).round() as i128;
+        let (min, max) = if self.signed {
+            (
+                -(1_i128 << (self.width() - 1)),
+                (1_i128 << (self.width() - 1)) - 1,
+            )
+        } else {
+            (0, (1_i128 << self.width()) - 1)
+        };
+        if raw < min || raw > max {
+            return Err(ParseError::OutOfRange(format!("{real} in {self}")));
+        }
+        Ok(raw)
+    }
+}
+
+impl Display for QFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let prefix = if self.signed { "Q" } else { "UQ" };
+        write!(f, "{prefix}{}.{}", self.int_bits, self.frac_bits)
+    }
+}
+
+/// Parses inputs such as `0.5q15` into the raw bits of the given fixed-point
+/// format. Returns `None` if the input is not in that form.
+pub fn try_parse_input(normalized_input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    let regex = Regex::new(FIXED_POINT_INPUT_REGEX).unwrap();
+    let captures = regex.captures(normalized_input)?;
+
+    let parsed = QFormat::from_input(&captures["format"]).and_then(|format| {
+        let sign = Sign::from_input(captures.name("sign").map_or("", |m| m.as_str()));
+        let magnitude = captures["real"].parse::<f64>().unwrap();
+        let real = if sign.is_neg() { -magnitude } else { magnitude };
+        let raw = format.encode(real)?;
+        let sign = if raw < 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Ok(ParsedUserInput::from_value(
+            normalized_input.to_owned(),
+            NumeralSystem::Decimal,
+            sign,
+            raw.unsigned_abs() as u64,
+        ))
+    });
+    Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_q_format() {
+        assert_eq!(
+            QFormat::new(true, 1, 15),
+            QFormat::from_input("q15").unwrap()
+        );
+        assert_eq!(
+            QFormat::new(true, 16, 16),
+            QFormat::from_input("q16.16").unwrap()
+        );
+        assert_eq!(
+            QFormat::new(false, 8, 8),
+            QFormat::from_input("uq8.8").unwrap()
+        );
+        assert_eq!(
+            QFormat::new(false, 0, 16),
+            QFormat::from_input("uq16").unwrap()
+        );
+        assert!(QFormat::from_input("q0.8").is_err());
+        assert!(QFormat::from_input("q32.33").is_err());
+        assert!(QFormat::from_input("x15").is_err());
+        assert_eq!("UQ8.8", QFormat::from_input("uq8.8").unwrap().to_string());
+    }
+
+    #[test]
+    fn test_encode() {
+        let q15 = QFormat::from_input("q15").unwrap();
+        assert_eq!(16384, q15.encode(0.5).unwrap());
+        assert_eq!(-32768, q15.encode(-1.0).unwrap());
+        assert!(q15.encode(1.0).is_err());
+        let uq8_8 = QFormat::from_input("uq8.8").unwrap();
+        assert_eq!(0x0180, uq8_8.encode(1.5).unwrap());
+        assert!(uq8_8.encode(-0.5).is_err());
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        let parsed = try_parse_input("-0.5q15").unwrap().unwrap();
+        assert_eq!(Sign::Negative, parsed.sign());
+        assert_eq!(16384, parsed.value());
+        assert_eq!(0xffff_ffff_ffff_c000, parsed.bits());
+        assert!(try_parse_input("0x10").is_none());
+        assert!(try_parse_input("2q15").unwrap().is_err());
+    }
+}
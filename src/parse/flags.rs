@@ -0,0 +1,339 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Tables of named flags, such as the flags of `open(2)`, and a generic
+//! decoder and parser for flag sets, such as `O_RDWR|O_CREAT`. All values
+//! are those of Linux on x86_64; the names of real-time signals follow
+//! glibc.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+
+/// A named flag or a named value of a multi-bit field in a flag set. It
+/// matches a value `v` if `v & mask == value`.
+#[derive(Debug, Copy, Clone)]
+pub struct Flag {
+    name: &'static str,
+    mask: u64,
+    value: u64,
+}
+
+/// A flag that is a single bit or a combination of bits.
+const fn flag(name: &'static str, value: u64) -> Flag {
+    Flag {
+        name,
+        mask: value,
+        value,
+    }
+}
+
+/// A named value of the multi-bit field `mask`, such as `O_RDONLY`.
+const fn field(name: &'static str, mask: u64, value: u64) -> Flag {
+    Flag { name, mask, value }
+}
+
+/// A table of flags that belong together, such as the flags of `open(2)`.
+#[derive(Debug)]
+pub struct FlagTable {
+    flags: &'static [Flag],
+}
+
+impl FlagTable {
+    /// Decodes a value into the names of its flags, such as
+    /// `O_RDWR|O_CREAT`. Flags are matched in table order; bits that are
+    /// not covered by the table are appended as hex number.
+    pub fn decode(&self, value: u64) -> String {
+        let mut names = Vec::new();
+        let mut consumed = 0;
+        for flag in self.flags {
+            if flag.mask & consumed == 0 && value & flag.mask == flag.value {
+                names.push(flag.name.to_string());
+                consumed |= flag.mask;
+            }
+        }
+        let unknown = value & !consumed;
+        if unknown != 0 {
+            names.push(format!("{unknown:#x}"));
+        }
+        if names.is_empty() {
+            "0".to_string()
+        } else {
+            names.join("|")
+        }
+    }
+
+    /// Looks up the value of a flag by its name.
+    fn lookup(&self, name: &str) -> Option<u64> {
+        self.flags
+            .iter()
+            .find(|flag| flag.name == name)
+            .map(|flag| flag.value)
+    }
+}
+
+/// Flags of `open(2)`.
+pub const OPEN_FLAGS: FlagTable = FlagTable {
+    flags: &[
+        field("O_RDONLY", 0o3, 0o0),
+        field("O_WRONLY", 0o3, 0o1),
+        field("O_RDWR", 0o3, 0o2),
+        flag("O_CREAT", 0o100),
+        flag("O_EXCL", 0o200),
+        flag("O_NOCTTY", 0o400),
+        flag("O_TRUNC", 0o1000),
+        flag("O_APPEND", 0o2000),
+        flag("O_NONBLOCK", 0o4000),
+        flag("O_SYNC", 0o4010000),
+        flag("O_DSYNC", 0o10000),
+        flag("O_ASYNC", 0o20000),
+        flag("O_DIRECT", 0o40000),
+        flag("O_LARGEFILE", 0o100000),
+        flag("O_TMPFILE", 0o20200000),
+        flag("O_DIRECTORY", 0o200000),
+        flag("O_NOFOLLOW", 0o400000),
+        flag("O_NOATIME", 0o1000000),
+        flag("O_CLOEXEC", 0o2000000),
+        flag("O_PATH", 0o10000000),
+    ],
+};
+
+/// Memory protection flags of `mmap(2)` and `mprotect(2)`.
+pub const MMAP_PROT: FlagTable = FlagTable {
+    flags: &[
+        flag("PROT_READ", 0x1),
+        flag("PROT_WRITE", 0x2),
+        flag("PROT_EXEC", 0x4),
+        flag("PROT_SEM", 0x8),
+        field("PROT_NONE", 0xf, 0x0),
+        flag("PROT_GROWSDOWN", 0x0100_0000),
+        flag("PROT_GROWSUP", 0x0200_0000),
+    ],
+};
+
+/// Flags of `mmap(2)`.
+pub const MMAP_FLAGS: FlagTable = FlagTable {
+    flags: &[
+        field("MAP_SHARED", 0x3, 0x1),
+        field("MAP_PRIVATE", 0x3, 0x2),
+        field("MAP_SHARED_VALIDATE", 0x3, 0x3),
+        flag("MAP_FIXED", 0x10),
+        flag("MAP_ANONYMOUS", 0x20),
+        flag("MAP_32BIT", 0x40),
+        flag("MAP_GROWSDOWN", 0x100),
+        flag("MAP_DENYWRITE", 0x800),
+        flag("MAP_EXECUTABLE", 0x1000),
+        flag("MAP_LOCKED", 0x2000),
+        flag("MAP_NORESERVE", 0x4000),
+        flag("MAP_POPULATE", 0x8000),
+        flag("MAP_NONBLOCK", 0x10000),
+        flag("MAP_STACK", 0x20000),
+        flag("MAP_HUGETLB", 0x40000),
+        flag("MAP_SYNC", 0x80000),
+        flag("MAP_FIXED_NOREPLACE", 0x10_0000),
+        flag("MAP_UNINITIALIZED", 0x400_0000),
+    ],
+};
+
+/// File type and mode bits of `st_mode`, see `inode(7)`.
+pub const FILE_MODE: FlagTable = FlagTable {
+    flags: &[
+        field("S_IFSOCK", 0o170000, 0o140000),
+        field("S_IFLNK", 0o170000, 0o120000),
+        field("S_IFREG", 0o170000, 0o100000),
+        field("S_IFBLK", 0o170000, 0o060000),
+        field("S_IFDIR", 0o170000, 0o040000),
+        field("S_IFCHR", 0o170000, 0o020000),
+        field("S_IFIFO", 0o170000, 0o010000),
+        flag("S_ISUID", 0o4000),
+        flag("S_ISGID", 0o2000),
+        flag("S_ISVTX", 0o1000),
+        flag("S_IRWXU", 0o700),
+        flag("S_IRUSR", 0o400),
+        flag("S_IWUSR", 0o200),
+        flag("S_IXUSR", 0o100),
+        flag("S_IRWXG", 0o70),
+        flag("S_IRGRP", 0o40),
+        flag("S_IWGRP", 0o20),
+        flag("S_IXGRP", 0o10),
+        flag("S_IRWXO", 0o7),
+        flag("S_IROTH", 0o4),
+        flag("S_IWOTH", 0o2),
+        flag("S_IXOTH", 0o1),
+    ],
+};
+
+/// Linux signal numbers and their names. Real-time signals are not listed.
+pub const SIGNALS: [(u8, &str); 31] = [
+    (1, "SIGHUP"),
+    (2, "SIGINT"),
+    (3, "SIGQUIT"),
+    (4, "SIGILL"),
+    (5, "SIGTRAP"),
+    (6, "SIGABRT"),
+    (7, "SIGBUS"),
+    (8, "SIGFPE"),
+    (9, "SIGKILL"),
+    (10, "SIGUSR1"),
+    (11, "SIGSEGV"),
+    (12, "SIGUSR2"),
+    (13, "SIGPIPE"),
+    (14, "SIGALRM"),
+    (15, "SIGTERM"),
+    (16, "SIGSTKFLT"),
+    (17, "SIGCHLD"),
+    (18, "SIGCONT"),
+    (19, "SIGSTOP"),
+    (20, "SIGTSTP"),
+    (21, "SIGTTIN"),
+    (22, "SIGTTOU"),
+    (23, "SIGURG"),
+    (24, "SIGXCPU"),
+    (25, "SIGXFSZ"),
+    (26, "SIGVTALRM"),
+    (27, "SIGPROF"),
+    (28, "SIGWINCH"),
+    (29, "SIGIO"),
+    (30, "SIGPWR"),
+    (31, "SIGSYS"),
+];
+
+/// All tables that are searched for names in flag set inputs.
+const FLAG_TABLES: [&FlagTable; 4] = [&OPEN_FLAGS, &MMAP_PROT, &MMAP_FLAGS, &FILE_MODE];
+
+/// Prefixes of the names in [`FLAG_TABLES`] and [`SIGNALS`]. Only tokens with
+/// one of them are names, so that inputs like `DEAD` remain numbers.
+const FLAG_PREFIXES: [&str; 5] = ["O_", "PROT_", "MAP_", "S_I", "SIG"];
+
+/// Looks up the name of a signal, including real-time signals. Real-time
+/// signals are numbered as in glibc and `kill -l`: the kernel's `SIGRTMIN`
+/// is 32, but glibc reserves 32 and 33 for itself, so that `SIGRTMIN` is 34.
+pub fn signal_name(signal: u64) -> Option<String> {
+    match signal {
+        34..=64 => Some(format!("SIGRTMIN+{}", signal - 34)),
+        _ => SIGNALS
+            .iter()
+            .find(|(number, _)| u64::from(*number) == signal)
+            .map(|(_, name)| name.to_string()),
+    }
+}
+
+/// Parses flag sets, such as `O_RDWR|O_CREAT` or `S_IFDIR|0o755`, into the
+/// combined value. Signal names stand for their bit in a signal mask. Returns
+/// `None` if the input is not a flag set.
+pub fn try_parse_input(input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    let input = input.trim();
+    let tokens = input.split('|').map(str::trim).collect::<Vec<_>>();
+    let is_name = |token: &str| {
+        FLAG_PREFIXES.iter().any(|prefix| token.starts_with(prefix))
+            && token
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+    };
+    let is_flag_set = tokens.iter().any(|token| is_name(token))
+        && tokens
+            .iter()
+            .all(|token| is_name(token) || parse_number(token).is_some());
+    if !is_flag_set {
+        return None;
+    }
+
+    let parsed = tokens
+        .iter()
+        .try_fold(0, |value, token| {
+            parse_number(token)
+                .or_else(|| lookup_flag(token))
+                .map(|flag| value | flag)
+                .ok_or_else(|| ParseError::InvalidFormat(format!("Unknown flag: {token}")))
+        })
+        .map(|value| {
+            ParsedUserInput::from_value(input.to_owned(), NumeralSystem::Hex, Sign::Positive, value)
+        });
+    Some(parsed)
+}
+
+fn lookup_flag(name: &str) -> Option<u64> {
+    FLAG_TABLES
+        .iter()
+        .find_map(|table| table.lookup(name))
+        .or_else(|| {
+            SIGNALS
+                .iter()
+                .find(|(_, signal_name)| *signal_name == name)
+                .map(|(number, _)| 1 << (number - 1))
+        })
+}
+
+/// Parses numbers in flag sets: hexadecimal (`0x`), octal (`0o` or a
+/// leading `0`, as in C), or decimal.
+fn parse_number(token: &str) -> Option<u64> {
+    let (digits, radix) = [("0x", 16), ("0o", 8), ("0", 8)]
+        .iter()
+        .find_map(|(prefix, radix)| {
+            token
+                .strip_prefix(prefix)
+                .filter(|digits| !digits.is_empty())
+                .map(|digits| (digits, *radix))
+        })
+        .unwrap_or((token, 10));
+    u64::from_str_radix(digits, radix).ok()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!("O_RDONLY", OPEN_FLAGS.decode(0));
+        assert_eq!("O_RDWR|O_CREAT|O_CLOEXEC", OPEN_FLAGS.decode(0o2000102));
+        assert_eq!("O_WRONLY|O_SYNC", OPEN_FLAGS.decode(0o4010001));
+        assert_eq!("PROT_NONE", MMAP_PROT.decode(0));
+        assert_eq!("PROT_READ|PROT_WRITE", MMAP_PROT.decode(3));
+        assert_eq!(
+            "MAP_PRIVATE|MAP_ANONYMOUS|0x80000000",
+            MMAP_FLAGS.decode(0x8000_0022)
+        );
+        assert_eq!("0", MMAP_FLAGS.decode(0));
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        let parse = |input| try_parse_input(input).unwrap().map(|parsed| parsed.value());
+        assert_eq!(0o102, parse("O_RDWR|O_CREAT").unwrap());
+        assert_eq!(0o40755, parse("S_IFDIR | 0755").unwrap());
+        assert_eq!(0x22, parse("MAP_PRIVATE|MAP_ANONYMOUS").unwrap());
+        assert_eq!(0x4002, parse("SIGINT|SIGTERM").unwrap());
+        assert!(parse("O_RDWR|O_FOO").is_err());
+        assert!(try_parse_input("42").is_none());
+        assert!(try_parse_input("0xDEAD").is_none());
+        assert!(try_parse_input("1MB").is_none());
+        assert!(try_parse_input("_IOR('T', 1, 4)").is_none());
+        assert!(try_parse_input("DEAD").is_none());
+        assert!(try_parse_input("FF").is_none());
+        assert!(try_parse_input("E").is_none());
+        assert!(try_parse_input("FF|O_RDWR").is_none());
+    }
+}
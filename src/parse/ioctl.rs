@@ -0,0 +1,183 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for Linux ioctl request numbers, written as the C macros
+//! `_IO`, `_IOR`, `_IOW`, and `_IOWR`.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+
+/// Number of bits of the `nr` field of an ioctl request number.
+pub const IOC_NRBITS: u32 = 8;
+/// Number of bits of the `type` field of an ioctl request number.
+pub const IOC_TYPEBITS: u32 = 8;
+/// Number of bits of the `size` field of an ioctl request number.
+pub const IOC_SIZEBITS: u32 = 14;
+/// Shift of the `type` field of an ioctl request number.
+pub const IOC_TYPESHIFT: u32 = IOC_NRBITS;
+/// Shift of the `size` field of an ioctl request number.
+pub const IOC_SIZESHIFT: u32 = IOC_TYPESHIFT + IOC_TYPEBITS;
+/// Shift of the `dir` field of an ioctl request number.
+pub const IOC_DIRSHIFT: u32 = IOC_SIZESHIFT + IOC_SIZEBITS;
+
+/// Sizes of C types that are commonly used in `sizeof()` in ioctl macros,
+/// for a 64 bit Linux target. Pointers, such as `void *`, are not listed,
+/// as all of them have [`POINTER_SIZE`].
+const C_TYPE_SIZES: [(&str, u64); 13] = [
+    ("char", 1),
+    ("short", 2),
+    ("int", 4),
+    ("long", 8),
+    ("__u8", 1),
+    ("__u16", 2),
+    ("__u32", 4),
+    ("__u64", 8),
+    ("u8", 1),
+    ("u16", 2),
+    ("u32", 4),
+    ("u64", 8),
+    ("size_t", 8),
+];
+
+/// Size of any pointer type, for a 64 bit Linux target.
+const POINTER_SIZE: u64 = 8;
+
+/// Parses ioctl macros, such as `_IOR('T', 1, 4)` or `_IO(0x54, 0x01)`, into
+/// the ioctl request number. Returns `None` if the input is not an ioctl
+/// macro.
+pub fn try_parse_input(input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    let input = input.trim();
+    let rest = input.strip_prefix("_IO")?;
+    let (dir_str, rest) = rest.split_once('(')?;
+    let dir = match dir_str {
+        "" => 0,
+        "W" => 1,
+        "R" => 2,
+        "WR" => 3,
+        _ => return None,
+    };
+    let Some(args) = rest.strip_suffix(')') else {
+        return Some(Err(invalid_macro(input)));
+    };
+
+    let args = args.split(',').map(str::trim).collect::<Vec<_>>();
+    let expected_args = if dir == 0 { 2 } else { 3 };
+    if args.len() != expected_args {
+        return Some(Err(invalid_macro(input)));
+    }
+
+    let parsed = parse_arg(args[0], input)
+        .and_then(|type_| check_range(type_, IOC_TYPEBITS, "type").map(|_| type_))
+        .and_then(|type_| {
+            let nr = parse_arg(args[1], input)?;
+            check_range(nr, IOC_NRBITS, "nr")?;
+            let size = args.get(2).map_or(Ok(0), |arg| parse_arg(arg, input))?;
+            check_range(size, IOC_SIZEBITS, "size")?;
+            Ok(dir << IOC_DIRSHIFT | size << IOC_SIZESHIFT | type_ << IOC_TYPESHIFT | nr)
+        })
+        .map(|value| {
+            ParsedUserInput::from_value(input.to_owned(), NumeralSystem::Hex, Sign::Positive, value)
+        });
+    Some(parsed)
+}
+
+/// Parses an argument of an ioctl macro: a character literal such as `'T'`,
+/// a `sizeof()` of a common C type, or a decimal or hexadecimal number.
+fn parse_arg(arg: &str, input: &str) -> Result<u64, ParseError> {
+    if let Some(c) = arg
+        .strip_prefix('\'')
+        .and_then(|arg| arg.strip_suffix('\''))
+    {
+        let mut chars = c.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii() => Ok(u64::from(c as u8)),
+            _ => Err(invalid_macro(input)),
+        };
+    }
+    if let Some(c_type) = arg
+        .strip_prefix("sizeof(")
+        .and_then(|arg| arg.strip_suffix(')'))
+    {
+        let c_type = c_type.trim();
+        if c_type.ends_with('*') {
+            return Ok(POINTER_SIZE);
+        }
+        return C_TYPE_SIZES
+            .iter()
+            .find(|(name, _)| *name == c_type)
+            .map(|(_, size)| *size)
+            .ok_or_else(|| ParseError::InvalidFormat(format!("Unknown C type: {c_type}")));
+    }
+    let number = arg
+        .strip_prefix("0x")
+        .or_else(|| arg.strip_prefix("0X"))
+        .map_or_else(|| arg.parse::<u64>(), |hex| u64::from_str_radix(hex, 16));
+    number.map_err(|_| invalid_macro(input))
+}
+
+fn check_range(value: u64, bits: u32, field: &str) -> Result<(), ParseError> {
+    if value >> bits == 0 {
+        Ok(())
+    } else {
+        Err(ParseError::OutOfRange(format!(
+            "ioctl {field} {value} doesn't fit into {bits} bit"
+        )))
+    }
+}
+
+fn invalid_macro(input: &str) -> ParseError {
+    ParseError::InvalidFormat(format!("Invalid ioctl macro: {input}"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn parse(input: &str) -> Result<u64, ParseError> {
+        try_parse_input(input).unwrap().map(|parsed| parsed.value())
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        // TCGETS
+        assert_eq!(0x5401, parse("_IO('T', 1)").unwrap());
+        assert_eq!(0x8004_5401, parse("_IOR('T', 1, 4)").unwrap());
+        assert_eq!(0x4004_5401, parse("_IOW(0x54, 0x1, sizeof(int))").unwrap());
+        assert_eq!(0xc008_5401, parse("_IOWR('T',1,sizeof(long))").unwrap());
+        for pointer in ["void *", "void*", "int *", "struct foo **"] {
+            assert_eq!(
+                0x8008_5401,
+                parse(&format!("_IOR('T', 1, sizeof({pointer}))")).unwrap()
+            );
+        }
+        assert!(parse("_IOR('T', 1)").is_err());
+        assert!(parse("_IOR('T', 256, 4)").is_err());
+        assert!(parse("_IOR('T', 1, 0x4000)").is_err());
+        assert!(parse("_IOR('T', 1, sizeof(struct foo))").is_err());
+        assert!(try_parse_input("0x5401").is_none());
+        assert!(try_parse_input("_IOX('T', 1)").is_none());
+    }
+}
@@ -21,16 +21,31 @@ LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
+pub use crate::parse::byte_sequence::ByteSequenceInfo;
+pub use crate::parse::endianness::Endianness;
 use crate::parse::error::ParseError;
+pub use crate::parse::fixed_point::QFormat;
 pub use crate::parse::ns::NumeralSystem;
 use crate::parse::sign::Sign;
 use crate::parse::unit::Unit;
+pub use crate::parse::varint::VarintInfo;
 use regex::Regex;
+use std::num::IntErrorKind;
 
+mod byte_sequence;
+mod color;
+mod endianness;
 mod error;
+mod expression;
+mod file_mode;
+mod fixed_point;
+pub mod flags;
+pub mod ioctl;
+mod network;
 mod ns;
 mod sign;
 pub mod unit;
+pub mod varint;
 
 /// Regex using named capture groups to validate the input.
 /// Valid all are lower case inputs in all four known numeral systems
@@ -38,10 +53,51 @@ pub mod unit;
 pub const INPUT_REGEX: &str =
     "^(?P<sign>-)?(?P<ns>0(b|o|x){1})?(?P<value>[0-9abcdef]+)(?P<unit>[a-z]{1,4})?$";
 
+/// Options that influence how the input is interpreted.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct InputOptions {
+    /// Byte order used to assemble byte sequence inputs.
+    pub endianness: Endianness,
+    /// Width of byte sequence inputs in bits. If `None`, the width is given
+    /// by the number of bytes.
+    pub width: Option<u32>,
+}
+
 /// Takes the input, normalizes it, checks if it is valid
 /// and transform it into an usize value.
-pub fn parse_input(input: &str) -> Result<ParsedUserInput, ParseError> {
+pub fn parse_input(input: &str, options: &InputOptions) -> Result<ParsedUserInput, ParseError> {
+    // expressions combine other inputs, so they are parsed first
+    if let Some(parsed) = expression::try_parse_input(input, options) {
+        return parsed;
+    }
+    // alternative input forms that don't follow the integer syntax; byte
+    // sequences, variable-length integers, ioctl macros, and flag sets must
+    // be parsed before normalization, as they are case-sensitive
+    if let Some(parsed) = varint::try_parse_input(input) {
+        return parsed;
+    }
+    if let Some(parsed) = byte_sequence::try_parse_input(input, options) {
+        return parsed;
+    }
+    if let Some(parsed) = ioctl::try_parse_input(input) {
+        return parsed;
+    }
+    if let Some(parsed) = flags::try_parse_input(input) {
+        return parsed;
+    }
     let normalized_input = normalize_input(input);
+    if let Some(parsed) = fixed_point::try_parse_input(&normalized_input) {
+        return parsed;
+    }
+    if let Some(parsed) = network::try_parse_input(&normalized_input) {
+        return parsed;
+    }
+    if let Some(parsed) = file_mode::try_parse_input(&normalized_input) {
+        return parsed;
+    }
+    if let Some(parsed) = color::try_parse_input(&normalized_input) {
+        return parsed;
+    }
 
     // validate and get input split
     // via named regex capture groups
@@ -52,13 +108,7 @@ pub fn parse_input(input: &str) -> Result<ParsedUserInput, ParseError> {
     let sign = Sign::from_input(input_split.sign.unwrap_or(""));
     let value_str = input_split.value.unwrap().to_owned();
 
-    Ok(ParsedUserInput::new(
-        normalized_input,
-        numeral_system,
-        value_str,
-        unit,
-        sign,
-    ))
+    ParsedUserInput::new(normalized_input, numeral_system, value_str, unit, sign)
 }
 
 #[derive(Debug)]
@@ -94,34 +144,84 @@ pub struct ParsedUserInput {
     unit: Unit,
     sign: Sign,
     value: u64,
+    byte_sequence: Option<ByteSequenceInfo>,
+    varint: Option<VarintInfo>,
+    wide_bits: Option<u128>,
 }
 
 #[allow(dead_code)]
 impl ParsedUserInput {
+    /// Constructs the input from the digits that matched [`INPUT_REGEX`].
+    /// Fails if the digits don't match the numeral system or the value doesn't
+    /// fit in 64 bits.
     fn new(
         normalized_input: String,
         numeral_system: NumeralSystem,
         value_str: String,
         unit: Unit,
         sign: Sign,
-    ) -> Self {
+    ) -> Result<Self, ParseError> {
+        let out_of_range = || ParseError::OutOfRange(format!("{normalized_input} exceeds 64 bits"));
+        // the regex lets all hex digits through, such as `0b102`
         let value_in_unit = match numeral_system {
-            NumeralSystem::Bin => u64::from_str_radix(&value_str, 2).unwrap(),
-            NumeralSystem::Octal => u64::from_str_radix(&value_str, 8).unwrap(),
-            NumeralSystem::Decimal => value_str.parse::<u64>().unwrap(),
-            NumeralSystem::Hex => u64::from_str_radix(&value_str, 16).unwrap(),
-        };
-        let value = unit.value_to_base_u64(value_in_unit);
+            NumeralSystem::Bin => u64::from_str_radix(&value_str, 2),
+            NumeralSystem::Octal => u64::from_str_radix(&value_str, 8),
+            NumeralSystem::Decimal => value_str.parse::<u64>(),
+            NumeralSystem::Hex => u64::from_str_radix(&value_str, 16),
+        }
+        .map_err(|e| match e.kind() {
+            IntErrorKind::PosOverflow => out_of_range(),
+            _ => ParseError::InvalidFormat(format!(
+                "{value_str} has digits that aren't valid in {numeral_system}"
+            )),
+        })?;
+        let value = unit
+            .value_to_base_u64(value_in_unit)
+            .ok_or_else(out_of_range)?;
 
-        Self {
+        Ok(Self {
             normalized_input,
             numeral_system,
             unit,
             sign,
             value,
+            byte_sequence: None,
+            varint: None,
+            wide_bits: None,
+        })
+    }
+
+    /// Constructs the input from an already computed value in the base unit.
+    /// Used by alternative input forms, such as fixed-point numbers.
+    const fn from_value(
+        normalized_input: String,
+        numeral_system: NumeralSystem,
+        sign: Sign,
+        value: u64,
+    ) -> Self {
+        Self {
+            normalized_input,
+            numeral_system,
+            unit: Unit::Base,
+            sign,
+            value,
+            byte_sequence: None,
+            varint: None,
+            wide_bits: None,
         }
     }
 
+    /// Constructs the input from raw 64 bits, such as the value from the
+    /// bit editor of the TUI.
+    pub fn from_bits(bits: u64) -> Self {
+        Self::from_value(
+            format!("0x{bits:x}"),
+            NumeralSystem::Hex,
+            Sign::Positive,
+            bits,
+        )
+    }
+
     /// Getter for `normalized_input`.
     pub fn normalized_input(&self) -> &str {
         &self.normalized_input
@@ -144,12 +244,41 @@ impl ParsedUserInput {
         self.value
     }
 
+    /// The raw 64 bits of the input, i.e., the value with the sign applied
+    /// in two's complement. So `-1` becomes `0xffff_ffff_ffff_ffff`.
+    pub const fn bits(&self) -> u64 {
+        if self.sign.is_neg() {
+            self.value.wrapping_neg()
+        } else {
+            self.value
+        }
+    }
+
     /// Getter for `value_str`. The actual number
     /// but without numeral system or unit.
     pub const fn value_str(&self) -> u64 {
         self.value
     }
 
+    /// Getter for `byte_sequence`. Only present if the input was a byte
+    /// sequence, such as `de ad be ef`.
+    pub const fn byte_sequence(&self) -> Option<ByteSequenceInfo> {
+        self.byte_sequence
+    }
+
+    /// Getter for `varint`. Only present if the input was an encoded
+    /// variable-length integer, such as `uleb128 e5 8e 26`.
+    pub const fn varint(&self) -> Option<VarintInfo> {
+        self.varint
+    }
+
+    /// Getter for `wide_bits`. The full 128 bits of inputs that are wider
+    /// than 64 bit, such as IPv6 addresses. In that case, [`Self::value`]
+    /// holds the lower 64 bits.
+    pub const fn wide_bits(&self) -> Option<u128> {
+        self.wide_bits
+    }
+
     /// Getter for `unit`.
     #[allow(dead_code)]
     pub const fn unit(&self) -> Unit {
@@ -184,7 +313,7 @@ mod tests {
 
     #[test]
     fn test_parse_input_1() {
-        let parsed = parse_input("-0xFMib").unwrap();
+        let parsed = parse_input("-0xFMib", &InputOptions::default()).unwrap();
         assert_eq!(Sign::Negative, parsed.sign());
         assert_eq!(15 * 1024 * 1024, parsed.value());
         assert_eq!(NumeralSystem::Hex, parsed.numeral_system());
@@ -193,13 +322,39 @@ mod tests {
 
     #[test]
     fn test_parse_input_2() {
-        let parsed = parse_input("-7").unwrap();
+        let parsed = parse_input("-7", &InputOptions::default()).unwrap();
         assert_eq!(Sign::Negative, parsed.sign());
         assert_eq!(7, parsed.value());
         assert_eq!(NumeralSystem::Decimal, parsed.numeral_system());
         assert_eq!(Unit::Base, parsed.unit());
     }
 
+    #[test]
+    fn test_parse_input_errors() {
+        let options = InputOptions::default();
+        assert_eq!(
+            u64::MAX,
+            parse_input("0xffffffffffffffff", &options).unwrap().value()
+        );
+        assert_eq!(1 << 44, parse_input("16tib", &options).unwrap().value());
+        for input in [
+            "18446744073709551616",
+            "0x1_0000_0000_0000_0000",
+            "16777216tib",
+        ] {
+            assert!(matches!(
+                parse_input(input, &options),
+                Err(ParseError::OutOfRange(_))
+            ));
+        }
+        // upper-case hex digits are not mistaken for flag names
+        assert!(matches!(
+            parse_input("DEAD", &options),
+            Err(ParseError::InvalidFormat(msg)) if msg.contains("aren't valid in Dec")
+        ));
+        assert_eq!(0xff, parse_input("0xFF", &options).unwrap().value());
+    }
+
     #[test]
     fn test_regex() {
         let regex = Regex::new(INPUT_REGEX).unwrap();
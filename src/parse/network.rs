@@ -0,0 +1,99 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Parsing code for IPv4 and IPv6 addresses.
+
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+use regex::Regex;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Regex for inputs that look like a dotted-quad IPv4 address.
+pub const IPV4_INPUT_REGEX: &str = "^[0-9]+\\.[0-9]+\\.[0-9]+\\.[0-9]+$";
+
+/// Parses IPv4 addresses, such as `192.168.1.1`, and IPv6 addresses, such as
+/// `fe80::1`. The address is interpreted in network byte order, i.e., the
+/// first octet is the most significant byte. Returns `None` if the input
+/// doesn't look like an IP address.
+pub fn try_parse_input(normalized_input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    if Regex::new(IPV4_INPUT_REGEX)
+        .unwrap()
+        .is_match(normalized_input)
+    {
+        let parsed = normalized_input
+            .parse::<Ipv4Addr>()
+            .map(|address| from_bits(normalized_input, u128::from(address.to_bits())))
+            .map_err(|_| invalid_address(normalized_input));
+        return Some(parsed);
+    }
+    if normalized_input.contains(':') {
+        let parsed = normalized_input
+            .parse::<Ipv6Addr>()
+            .map(|address| {
+                let bits = address.to_bits();
+                let mut parsed = from_bits(normalized_input, bits);
+                parsed.wide_bits = Some(bits);
+                parsed
+            })
+            .map_err(|_| invalid_address(normalized_input));
+        return Some(parsed);
+    }
+    None
+}
+
+fn from_bits(normalized_input: &str, bits: u128) -> ParsedUserInput {
+    ParsedUserInput::from_value(
+        normalized_input.to_owned(),
+        NumeralSystem::Hex,
+        Sign::Positive,
+        bits as u64,
+    )
+}
+
+fn invalid_address(normalized_input: &str) -> ParseError {
+    ParseError::InvalidFormat(format!("Invalid IP address: {normalized_input}"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_try_parse_input() {
+        let parsed = try_parse_input("192.168.1.1").unwrap().unwrap();
+        assert_eq!(0xc0a8_0101, parsed.value());
+        assert!(parsed.wide_bits().is_none());
+
+        let parsed = try_parse_input("fe80::1").unwrap().unwrap();
+        assert_eq!(1, parsed.value());
+        assert_eq!(Some(0xfe80 << 112 | 1), parsed.wide_bits());
+
+        assert!(try_parse_input("256.0.0.1").unwrap().is_err());
+        assert!(try_parse_input("fe80:::1").unwrap().is_err());
+        assert!(try_parse_input("0xdeadbeef").is_none());
+        assert!(try_parse_input("1.5q15").is_none());
+    }
+}
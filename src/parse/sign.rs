@@ -55,7 +55,6 @@ impl Sign {
     }
 
     /// Convenient function to check if the sign is positive.
-    #[allow(dead_code)]
     pub const fn is_neg(&self) -> bool {
         !self.is_pos()
     }
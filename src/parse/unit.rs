@@ -70,18 +70,18 @@ impl Unit {
         Ok(x)
     }
 
-    /// Transforms a value in a specific value into the base unit.
-    pub fn base_to_target(self, value: f64) -> f64 {
+    /// The number of bytes in one unit, such as 1024 for KiB.
+    pub const fn bytes(self) -> u64 {
         match self {
-            Self::Base => value,
-            Self::Kilo => value / 1E3_f64,
-            Self::Mega => value / 1E6_f64,
-            Self::Giga => value / 1E9_f64,
-            Self::Tera => value / 1E12_f64,
-            Self::Kibi => value / 1024_f64,
-            Self::Mibi => value / 1024_f64.powi(2),
-            Self::Gibi => value / 1024_f64.powi(3),
-            Self::Tebi => value / 1024_f64.powi(4),
+            Self::Base => 1,
+            Self::Kilo => 1E3 as u64,
+            Self::Mega => 1E6 as u64,
+            Self::Giga => 1E9 as u64,
+            Self::Tera => 1E12 as u64,
+            Self::Kibi => 1024_u64,
+            Self::Mibi => 1024_u64.pow(2),
+            Self::Gibi => 1024_u64.pow(3),
+            Self::Tebi => 1024_u64.pow(4),
         }
     }
 
@@ -98,19 +98,10 @@ impl Unit {
     }*/
 
     /// Converts the integer input to the base unit.
-    /// This is fine as long as we don't support fractional input
-    pub const fn value_to_base_u64(self, value: u64) -> u64 {
-        match self {
-            Self::Base => value,
-            Self::Kilo => value * 1E3 as u64,
-            Self::Mega => value * 1E6 as u64,
-            Self::Giga => value * 1E9 as u64,
-            Self::Tera => value * 1E12 as u64,
-            Self::Kibi => value * 1024_u64,
-            Self::Mibi => value * 1024_u64.pow(2),
-            Self::Gibi => value * 1024_u64.pow(3),
-            Self::Tebi => value * 1024_u64.pow(4),
-        }
+    /// This is fine as long as we don't support fractional input.
+    /// Returns `None` if the value in the base unit doesn't fit in 64 bits.
+    pub const fn value_to_base_u64(self, value: u64) -> Option<u64> {
+        value.checked_mul(self.bytes())
     }
 }
 
@@ -0,0 +1,423 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Variable-length integer encodings, such as LEB128, protobuf varints,
+//! QUIC variable-length integers, and UTF-8, and parsing code for encoded
+//! inputs, such as `uleb128 e5 8e 26`.
+
+use crate::parse::byte_sequence::try_parse_bytes;
+use crate::parse::error::ParseError;
+use crate::parse::sign::Sign;
+use crate::parse::{NumeralSystem, ParsedUserInput};
+use derive_more::Display;
+use std::fmt::{Display as FmtDisplay, Formatter};
+
+/// Largest value of a QUIC variable-length integer.
+const QUIC_MAX: u64 = (1 << 62) - 1;
+
+/// A variable-length integer encoding.
+#[derive(Debug, Display, Copy, Clone, PartialEq, Eq)]
+pub enum VarintFormat {
+    #[display("ULEB128")]
+    Uleb128,
+    #[display("SLEB128")]
+    Sleb128,
+    /// Protobuf `int64`/`uint64`. The bytes are the same as ULEB128 of the
+    /// raw bits, but at most 10 bytes.
+    #[display("protobuf varint")]
+    Protobuf,
+    /// Protobuf `sint64`: zigzag encoding followed by a varint.
+    #[display("protobuf sint64")]
+    ProtobufSint64,
+    /// QUIC variable-length integer (RFC 9000): a 2 bit length prefix
+    /// followed by the value in big endian.
+    #[display("QUIC")]
+    Quic,
+    /// A Unicode code point in UTF-8 (RFC 3629). Overlong encodings and
+    /// surrogates are invalid.
+    #[display("UTF-8")]
+    Utf8,
+}
+
+impl VarintFormat {
+    pub const ALL: [Self; 6] = [
+        Self::Uleb128,
+        Self::Sleb128,
+        Self::Protobuf,
+        Self::ProtobufSint64,
+        Self::Quic,
+        Self::Utf8,
+    ];
+
+    /// Parses the name of a format, as used as input prefix. Returns `None`
+    /// for unknown names.
+    pub fn from_input(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "uleb128" | "leb128" => Some(Self::Uleb128),
+            "sleb128" => Some(Self::Sleb128),
+            "varint" | "protobuf" => Some(Self::Protobuf),
+            "sint64" | "zigzag" => Some(Self::ProtobufSint64),
+            "quic" => Some(Self::Quic),
+            "utf8" | "utf-8" => Some(Self::Utf8),
+            _ => None,
+        }
+    }
+
+    /// Encodes the raw 64 bits of a value. Signed formats interpret them in
+    /// two's complement. Returns `None` if the value is out of range of the
+    /// format.
+    pub fn encode(self, bits: u64) -> Option<Vec<u8>> {
+        match self {
+            Self::Uleb128 | Self::Protobuf => Some(encode_uleb128(bits)),
+            Self::Sleb128 => Some(encode_sleb128(bits as i64)),
+            Self::ProtobufSint64 => Some(encode_uleb128(zigzag_encode(bits as i64))),
+            Self::Quic => encode_quic(bits),
+            Self::Utf8 => u32::try_from(bits)
+                .ok()
+                .and_then(char::from_u32)
+                .map(|c| c.to_string().into_bytes()),
+        }
+    }
+
+    /// Decodes a value from the start of `bytes`. Returns the value as raw
+    /// 64 bits and the number of consumed bytes.
+    fn decode(self, bytes: &[u8]) -> Result<(u64, usize), ParseError> {
+        match self {
+            Self::Uleb128 | Self::Protobuf => decode_leb128(bytes, false),
+            Self::Sleb128 => decode_leb128(bytes, true),
+            Self::ProtobufSint64 => decode_leb128(bytes, false)
+                .map(|(bits, consumed)| (zigzag_decode(bits) as u64, consumed)),
+            Self::Quic => decode_quic(bytes),
+            Self::Utf8 => decode_utf8(bytes),
+        }
+    }
+
+    const fn is_signed(self) -> bool {
+        matches!(self, Self::Sleb128 | Self::ProtobufSint64)
+    }
+}
+
+/// Describes how a variable-length integer input was decoded.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct VarintInfo {
+    format: VarintFormat,
+    consumed: usize,
+    count: usize,
+}
+
+impl FmtDisplay for VarintInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {} of {} byte{} consumed",
+            self.format,
+            self.consumed,
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Parses encoded variable-length integers: the name of a [`VarintFormat`]
+/// followed by a byte sequence, such as `uleb128 e5 8e 26` or
+/// `quic [0x40, 0x25]`. Bytes after the end of the encoded value are
+/// ignored. Returns `None` if the input doesn't start with a format name.
+pub fn try_parse_input(input: &str) -> Option<Result<ParsedUserInput, ParseError>> {
+    let (name, bytes_input) = input.trim().split_once(char::is_whitespace)?;
+    let format = VarintFormat::from_input(name)?;
+
+    let parsed = parse_single_byte(bytes_input)
+        .or_else(|| try_parse_bytes(bytes_input))
+        .unwrap_or_else(|| {
+            Err(ParseError::InvalidFormat(format!(
+                "Invalid byte sequence: {bytes_input}"
+            )))
+        })
+        .and_then(|bytes| {
+            let (bits, consumed) = format.decode(&bytes)?;
+            let (sign, value) = if format.is_signed() && (bits as i64) < 0 {
+                (Sign::Negative, (bits as i64).unsigned_abs())
+            } else {
+                (Sign::Positive, bits)
+            };
+            let mut parsed = ParsedUserInput::from_value(
+                input.trim().to_owned(),
+                NumeralSystem::Hex,
+                sign,
+                value,
+            );
+            parsed.varint = Some(VarintInfo {
+                format,
+                consumed,
+                count: bytes.len(),
+            });
+            Ok(parsed)
+        });
+    Some(parsed)
+}
+
+/// A single hex byte, such as `96`, is not a byte sequence on its own but
+/// a valid encoding of small values.
+fn parse_single_byte(input: &str) -> Option<Result<Vec<u8>, ParseError>> {
+    let input = input.trim();
+    let digits = input.strip_prefix("0x").unwrap_or(input);
+    (digits.len() == 2)
+        .then(|| u8::from_str_radix(digits, 16).ok())
+        .flatten()
+        .map(|byte| Ok(vec![byte]))
+}
+
+/// Zigzag encoding as used by protobuf `sint64`: small negative and positive
+/// values get small encodings.
+pub const fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub const fn zigzag_decode(bits: u64) -> i64 {
+    (bits >> 1) as i64 ^ -((bits & 1) as i64)
+}
+
+fn encode_uleb128(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn encode_sleb128(mut value: i64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let sign_bit_clear = byte & 0x40 == 0;
+        if (value == 0 && sign_bit_clear) || (value == -1 && !sign_bit_clear) {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn encode_quic(value: u64) -> Option<Vec<u8>> {
+    let (len, prefix): (usize, u8) = match value {
+        0..=0x3f => (1, 0b00),
+        0x40..=0x3fff => (2, 0b01),
+        0x4000..=0x3fff_ffff => (4, 0b10),
+        0x4000_0000..=QUIC_MAX => (8, 0b11),
+        _ => return None,
+    };
+    let mut bytes = value.to_be_bytes()[8 - len..].to_vec();
+    bytes[0] |= prefix << 6;
+    Some(bytes)
+}
+
+/// Decodes LEB128 (and protobuf varints). Values that don't fit into 64 bit
+/// are rejected.
+fn decode_leb128(bytes: &[u8], signed: bool) -> Result<(u64, usize), ParseError> {
+    let mut value = 0_u64;
+    for (i, byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let payload = u64::from(byte & 0x7f);
+        if shift >= 64 || (shift > 57 && payload >> (64 - shift) != 0 && !signed) {
+            return Err(ParseError::OutOfRange(
+                "Variable-length integer doesn't fit into 64 bit".to_string(),
+            ));
+        }
+        value |= payload << shift;
+        if byte & 0x80 == 0 {
+            let shift = shift + 7;
+            if signed && shift < 64 && byte & 0x40 != 0 {
+                value |= u64::MAX << shift;
+            }
+            return Ok((value, i + 1));
+        }
+    }
+    Err(ParseError::InvalidFormat(
+        "Unterminated variable-length integer".to_string(),
+    ))
+}
+
+fn decode_quic(bytes: &[u8]) -> Result<(u64, usize), ParseError> {
+    let first = bytes
+        .first()
+        .ok_or_else(|| ParseError::InvalidFormat("Empty byte sequence".to_string()))?;
+    let len = 1 << (first >> 6);
+    let encoded = bytes.get(..len).ok_or_else(|| {
+        ParseError::InvalidFormat(format!("QUIC variable-length integer needs {len} bytes"))
+    })?;
+    let value = encoded
+        .iter()
+        .fold(0, |value, byte| value << 8 | u64::from(*byte))
+        & (u64::MAX >> (64 - 8 * len + 2));
+    Ok((value, len))
+}
+
+/// Decodes the first code point of UTF-8 bytes. The length of the sequence
+/// is given by the first byte; invalid sequences, such as overlong
+/// encodings, are rejected.
+fn decode_utf8(bytes: &[u8]) -> Result<(u64, usize), ParseError> {
+    let first = bytes
+        .first()
+        .ok_or_else(|| ParseError::InvalidFormat("Empty byte sequence".to_string()))?;
+    let len = match first {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => {
+            return Err(ParseError::InvalidFormat(format!(
+                "Invalid first byte of a UTF-8 sequence: {first:02x}"
+            )));
+        }
+    };
+    let encoded = bytes
+        .get(..len)
+        .ok_or_else(|| ParseError::InvalidFormat(format!("UTF-8 sequence needs {len} bytes")))?;
+    let c = std::str::from_utf8(encoded)
+        .ok()
+        .and_then(|string| string.chars().next())
+        .ok_or_else(|| ParseError::InvalidFormat("Invalid UTF-8 sequence".to_string()))?;
+    Ok((u64::from(c), len))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_zigzag() {
+        assert_eq!(0, zigzag_encode(0));
+        assert_eq!(1, zigzag_encode(-1));
+        assert_eq!(2, zigzag_encode(1));
+        assert_eq!(u64::MAX, zigzag_encode(i64::MIN));
+        for value in [0, -1, 1, i64::MIN, i64::MAX] {
+            assert_eq!(value, zigzag_decode(zigzag_encode(value)));
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            Some(vec![0xe5, 0x8e, 0x26]),
+            VarintFormat::Uleb128.encode(624_485)
+        );
+        assert_eq!(
+            Some(vec![0xc0, 0xbb, 0x78]),
+            VarintFormat::Sleb128.encode(-123_456_i64 as u64)
+        );
+        assert_eq!(
+            Some(vec![0x7f]),
+            VarintFormat::Sleb128.encode(-1_i64 as u64)
+        );
+        assert_eq!(10, VarintFormat::Protobuf.encode(u64::MAX).unwrap().len());
+        assert_eq!(
+            Some(vec![0x03]),
+            VarintFormat::ProtobufSint64.encode(-2_i64 as u64)
+        );
+        assert_eq!(Some(vec![0x7b, 0xbd]), VarintFormat::Quic.encode(15_293));
+        assert_eq!(
+            Some(vec![0x9d, 0x7f, 0x3e, 0x7d]),
+            VarintFormat::Quic.encode(494_878_333)
+        );
+        assert_eq!(None, VarintFormat::Quic.encode(QUIC_MAX + 1));
+    }
+
+    #[test]
+    fn test_decode() {
+        for format in VarintFormat::ALL {
+            for bits in [0, 1, 63, 64, 624_485, -1_i64 as u64, QUIC_MAX] {
+                let Some(bytes) = format.encode(bits) else {
+                    continue;
+                };
+                assert_eq!((bits, bytes.len()), format.decode(&bytes).unwrap());
+            }
+        }
+        assert!(VarintFormat::Uleb128.decode(&[0x80, 0x80]).is_err());
+        assert!(VarintFormat::Uleb128.decode(&[0xff; 10]).is_err());
+        assert!(VarintFormat::Quic.decode(&[0x40]).is_err());
+
+        for (bytes, code_point) in [
+            (&[0x41][..], 0x41),
+            (&[0xc3, 0xa4], 0xe4),
+            (&[0xe2, 0x82, 0xac], 0x20ac),
+            (&[0xf0, 0x9f, 0x98, 0x80], 0x1f600),
+        ] {
+            assert_eq!(
+                (code_point, bytes.len()),
+                VarintFormat::Utf8.decode(bytes).unwrap()
+            );
+        }
+        for invalid in [
+            &[0x80][..],
+            &[0xff],
+            &[0xc3],
+            &[0xc3, 0x41],
+            &[0xc0, 0x80],
+            &[0xe0, 0x80, 0xaf],
+            &[0xed, 0xa0, 0x80],
+            &[0xf4, 0x90, 0x80, 0x80],
+        ] {
+            assert!(
+                VarintFormat::Utf8.decode(invalid).is_err(),
+                "{invalid:02x?}"
+            );
+        }
+        assert_eq!(None, VarintFormat::Utf8.encode(0xd800));
+    }
+
+    #[test]
+    fn test_try_parse_input() {
+        let parsed = try_parse_input("uleb128 e5 8e 26 ff").unwrap().unwrap();
+        assert_eq!(624_485, parsed.value());
+        assert_eq!(
+            "ULEB128, 3 of 4 bytes consumed",
+            parsed.varint().unwrap().to_string()
+        );
+
+        let parsed = try_parse_input("SLEB128 [0x7f]").unwrap().unwrap();
+        assert!(parsed.sign().is_neg());
+        assert_eq!(1, parsed.value());
+
+        let parsed = try_parse_input("quic 25").unwrap().unwrap();
+        assert_eq!(37, parsed.value());
+
+        let parsed = try_parse_input("utf8 f0 9f 98 80 41").unwrap().unwrap();
+        assert_eq!(0x1f600, parsed.value());
+        assert_eq!(
+            "UTF-8, 4 of 5 bytes consumed",
+            parsed.varint().unwrap().to_string()
+        );
+        assert!(try_parse_input("utf-8 c0 80").unwrap().is_err());
+
+        assert!(try_parse_input("uleb128 xyz").unwrap().is_err());
+        assert!(try_parse_input("de ad be ef").is_none());
+    }
+}
@@ -0,0 +1,228 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for color interpretations.
+
+use super::{Interpretation, OutputGroup, OutputLine, ValueAlignment};
+use crate::parse::ParsedUserInput;
+
+/// Pixel formats that are shown in the color group.
+#[derive(Debug, Copy, Clone)]
+enum PixelFormat {
+    Rgb888,
+    Argb8888,
+    Rgba8888,
+    Rgb565,
+    Rgb555,
+}
+
+const PIXEL_FORMATS: [(PixelFormat, &str); 5] = [
+    (PixelFormat::Rgb888, "RGB888"),
+    (PixelFormat::Argb8888, "ARGB8888"),
+    (PixelFormat::Rgba8888, "RGBA8888"),
+    (PixelFormat::Rgb565, "RGB565"),
+    (PixelFormat::Rgb555, "RGB555"),
+];
+
+/// A color with 8 bit per component.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rgba {
+    /// Red.
+    pub r: u8,
+    /// Green.
+    pub g: u8,
+    /// Blue.
+    pub b: u8,
+    /// Alpha, if the pixel format has an alpha channel.
+    pub a: Option<u8>,
+}
+
+impl PixelFormat {
+    /// Decodes the color. Components with less than 8 bit are scaled to the
+    /// full 8 bit range.
+    fn decode(self, bits: u64) -> Rgba {
+        let component = |shift: u32| (bits >> shift) as u8;
+        let scale = |value: u64, bits: u32| {
+            ((value * 255 + ((1 << bits) - 1) / 2) / ((1 << bits) - 1)) as u8
+        };
+        match self {
+            Self::Rgb888 => Rgba {
+                r: component(16),
+                g: component(8),
+                b: component(0),
+                a: None,
+            },
+            Self::Argb8888 => Rgba {
+                r: component(16),
+                g: component(8),
+                b: component(0),
+                a: Some(component(24)),
+            },
+            Self::Rgba8888 => Rgba {
+                r: component(24),
+                g: component(16),
+                b: component(8),
+                a: Some(component(0)),
+            },
+            Self::Rgb565 => Rgba {
+                r: scale((bits >> 11) & 0x1f, 5),
+                g: scale((bits >> 5) & 0x3f, 6),
+                b: scale(bits & 0x1f, 5),
+                a: None,
+            },
+            Self::Rgb555 => Rgba {
+                r: scale((bits >> 10) & 0x1f, 5),
+                g: scale((bits >> 5) & 0x1f, 5),
+                b: scale(bits & 0x1f, 5),
+                a: None,
+            },
+        }
+    }
+}
+
+pub(super) fn build_colors_og(parsed: &ParsedUserInput) -> OutputGroup {
+    OutputGroup {
+        title: Interpretation::Colors,
+        value_alignment: ValueAlignment::Left,
+        lines: PIXEL_FORMATS
+            .iter()
+            .map(|(format, name)| {
+                OutputLine::new(name.to_string(), format_color(format.decode(parsed.bits())))
+            })
+            .collect(),
+    }
+}
+
+/// Returns the color of each line of the color group, so that a UI can draw
+/// a swatch next to it.
+pub fn color_swatches(parsed: &ParsedUserInput) -> Vec<Rgba> {
+    PIXEL_FORMATS
+        .iter()
+        .map(|(format, _)| format.decode(parsed.bits()))
+        .collect()
+}
+
+/// Formats the components of a color in hex, decimal, float, and HSL.
+fn format_color(color: Rgba) -> String {
+    let Rgba { r, g, b, a } = color;
+    let components = [r, g, b].into_iter().chain(a).collect::<Vec<_>>();
+    let join = |format: fn(u8) -> String| {
+        components
+            .iter()
+            .map(|c| format(*c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let hex = components
+        .iter()
+        .map(|c| format!("{c:02x}"))
+        .collect::<String>();
+    let function = if a.is_some() { "rgba" } else { "rgb" };
+    let (h, s, l) = rgb_to_hsl(r, g, b);
+    format!(
+        "#{hex} {function}({}) ({}) hsl({h}, {s}%, {l}%)",
+        join(|c| c.to_string()),
+        join(|c| format!("{:.3}", f64::from(c) / 255.0)),
+    )
+}
+
+/// Converts a color to HSL. Returns the hue in degrees and saturation and
+/// lightness in percent, all rounded.
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return (0, 0, (l * 100.0).round() as u8);
+    }
+    let s = delta / (1.0 - 2.0f64.mul_add(l, -1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (
+        h.round() as u16 % 360,
+        (s * 100.0).round() as u8,
+        (l * 100.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let orange = Rgba {
+            r: 255,
+            g: 136,
+            b: 0,
+            a: None,
+        };
+        assert_eq!(orange, PixelFormat::Rgb888.decode(0xff8800));
+        assert_eq!(
+            Rgba {
+                a: Some(0x80),
+                ..orange
+            },
+            PixelFormat::Argb8888.decode(0x80ff8800)
+        );
+        assert_eq!(
+            Rgba {
+                a: Some(0x80),
+                ..orange
+            },
+            PixelFormat::Rgba8888.decode(0xff880080)
+        );
+        let white = Rgba {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: None,
+        };
+        assert_eq!(white, PixelFormat::Rgb565.decode(0xffff));
+        assert_eq!(white, PixelFormat::Rgb555.decode(0x7fff));
+    }
+
+    #[test]
+    fn test_format_color() {
+        assert_eq!(
+            "#ff8800 rgb(255, 136, 0) (1.000, 0.533, 0.000) hsl(32, 100%, 50%)",
+            format_color(PixelFormat::Rgb888.decode(0xff8800))
+        );
+        assert_eq!(
+            "#00000080 rgba(0, 0, 0, 128) (0.000, 0.000, 0.000, 0.502) hsl(0, 0%, 0%)",
+            format_color(PixelFormat::Rgba8888.decode(0x80))
+        );
+    }
+}
@@ -0,0 +1,208 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for alternative integer encodings besides two's complement.
+
+use super::{Integer, Interpretation, OutputGroup, OutputLine, Value, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use crate::parse::varint::{zigzag_decode, zigzag_encode};
+
+/// Widths of the per-width rows, like in the signed integers group.
+const WIDTHS: [u32; 4] = [8, 16, 32, 64];
+
+pub(super) fn build_encodings_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+
+    let mut lines: Vec<(String, Value)> = vec![
+        ("BCD".to_string(), format_bcd(bits).into()),
+        ("BCD (encoded)".to_string(), format_bcd_encoded(bits).into()),
+        (
+            "Gray (encoded)".to_string(),
+            format_with_hex(gray_encode(bits)).into(),
+        ),
+        (
+            "Gray (decoded)".to_string(),
+            format_with_hex(gray_decode(bits)).into(),
+        ),
+        (
+            "zigzag (encoded)".to_string(),
+            Integer::decimal(i128::from(zigzag_encode(bits as i64)), 64, false).into(),
+        ),
+        (
+            "zigzag (decoded)".to_string(),
+            Integer::decimal(i128::from(zigzag_decode(bits)), 64, true).into(),
+        ),
+    ];
+    lines.extend(WIDTHS.iter().map(|width| {
+        (
+            format!("ones' i{width}"),
+            format_sign_and_magnitude(ones_complement(bits, *width)).into(),
+        )
+    }));
+    lines.extend(WIDTHS.iter().map(|width| {
+        (
+            format!("sign-mag i{width}"),
+            format_sign_and_magnitude(sign_magnitude(bits, *width)).into(),
+        )
+    }));
+    lines.extend(WIDTHS.iter().map(|width| {
+        let bias = excess_k_bias(*width);
+        let key = if bias > 1 << 16 {
+            format!("excess-2^{}", width - 1)
+        } else {
+            format!("excess-{bias}")
+        };
+        let value = i128::from(mask(bits, *width)) - bias;
+        (key, Integer::decimal(value, *width, true).into())
+    }));
+
+    OutputGroup {
+        title: Interpretation::AlternativeEncodings,
+        value_alignment: ValueAlignment::Left,
+        lines: lines
+            .into_iter()
+            .map(|(key, value)| OutputLine::new(key, value))
+            .collect(),
+    }
+}
+
+/// Returns the lower `width` bits.
+const fn mask(bits: u64, width: u32) -> u64 {
+    if width >= 64 {
+        bits
+    } else {
+        bits & ((1 << width) - 1)
+    }
+}
+
+/// Reads the value as packed BCD, i.e., one decimal digit per nibble.
+fn format_bcd(bits: u64) -> String {
+    let hex = format!("{bits:x}");
+    hex.chars()
+        .find(|c| !c.is_ascii_digit())
+        .map_or_else(|| hex.clone(), |c| format!("(invalid nibble 0x{c})"))
+}
+
+/// Encodes the value as packed BCD. Values with more than 16 decimal digits
+/// don't fit into 64 bits.
+fn format_bcd_encoded(bits: u64) -> String {
+    let decimal = bits.to_string();
+    if decimal.len() > 16 {
+        return "(more than 16 digits)".to_string();
+    }
+    format!("0x{decimal}")
+}
+
+const fn gray_encode(bits: u64) -> u64 {
+    bits ^ (bits >> 1)
+}
+
+const fn gray_decode(mut bits: u64) -> u64 {
+    let mut shift = 1;
+    while shift < 64 {
+        bits ^= bits >> shift;
+        shift <<= 1;
+    }
+    bits
+}
+
+/// Reads the lower `width` bits in ones' complement. Returns whether the
+/// value is negative and its magnitude, as there is a negative zero.
+const fn ones_complement(bits: u64, width: u32) -> (bool, u64) {
+    let value = mask(bits, width);
+    if value >> (width - 1) == 0 {
+        (false, value)
+    } else {
+        (true, mask(!value, width))
+    }
+}
+
+/// Reads the lower `width` bits as sign and magnitude. Returns whether the
+/// value is negative and its magnitude, as there is a negative zero.
+const fn sign_magnitude(bits: u64, width: u32) -> (bool, u64) {
+    let value = mask(bits, width);
+    (value >> (width - 1) == 1, mask(value, width - 1))
+}
+
+/// The bias of the usual excess-K encoding for the width, i.e., `2^(width-1)`.
+const fn excess_k_bias(width: u32) -> i128 {
+    1 << (width - 1)
+}
+
+fn format_sign_and_magnitude((is_negative, magnitude): (bool, u64)) -> String {
+    if is_negative {
+        format!("-{magnitude}")
+    } else {
+        magnitude.to_string()
+    }
+}
+
+fn format_with_hex(value: u64) -> String {
+    format!("{value} (0x{value:x})")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcd() {
+        assert_eq!("1234", format_bcd(0x1234));
+        assert_eq!("(invalid nibble 0xa)", format_bcd(0x12a4));
+        assert_eq!("0x1234", format_bcd_encoded(1234));
+        assert_eq!("(more than 16 digits)", format_bcd_encoded(u64::MAX));
+    }
+
+    #[test]
+    fn test_gray() {
+        assert_eq!(0b110, gray_encode(4));
+        for value in [0, 1, 4, 0xdead_beef, u64::MAX] {
+            assert_eq!(value, gray_decode(gray_encode(value)));
+        }
+    }
+
+    #[test]
+    fn test_signed_encodings() {
+        assert_eq!((true, 0), ones_complement(0xff, 8));
+        assert_eq!((true, 1), ones_complement(0xfe, 8));
+        assert_eq!((false, 127), ones_complement(0x7f, 8));
+        assert_eq!((true, 0), sign_magnitude(0x80, 8));
+        assert_eq!((true, 1), sign_magnitude(0x8001, 16));
+        assert_eq!((true, i64::MAX as u64), sign_magnitude(u64::MAX, 64));
+        assert_eq!("-0", format_sign_and_magnitude((true, 0)));
+    }
+
+    #[test]
+    fn test_encodings_og() {
+        let parsed = crate::parse::parse_input("0x80", &Default::default()).unwrap();
+        let og = build_encodings_og(&parsed);
+        let excess_128 = og
+            .lines
+            .iter()
+            .find(|line| line.key == "excess-128")
+            .unwrap();
+        assert_eq!("0", excess_128.value.format(&Default::default()));
+        assert_eq!(18, og.lines.len());
+    }
+}
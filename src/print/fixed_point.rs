@@ -0,0 +1,109 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for fixed-point (Q-format) interpretations.
+
+use super::{Interpretation, OutputGroup, OutputLine, OutputOptions, Value, ValueAlignment};
+use crate::parse::{ParsedUserInput, QFormat};
+
+pub(super) fn build_fixed_point_og(
+    parsed: &ParsedUserInput,
+    options: &OutputOptions,
+) -> OutputGroup {
+    // additional formats that are already shown are skipped
+    let mut formats = QFormat::COMMON.to_vec();
+    for format in &options.q_formats {
+        if !formats.contains(format) {
+            formats.push(*format);
+        }
+    }
+    OutputGroup {
+        title: Interpretation::FixedPoint,
+        // aligned at the decimal point when formatted
+        value_alignment: ValueAlignment::Left,
+        lines: formats
+            .iter()
+            .map(|format| {
+                OutputLine::new(
+                    format.to_string(),
+                    Value::Decimal(fixed_point_to_decimal(*format, parsed.bits())),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Interprets the lower `width` bits of `bits` as number in the given
+/// fixed-point format and returns its exact decimal representation.
+fn fixed_point_to_decimal(format: QFormat, bits: u64) -> String {
+    let width = format.width();
+    let raw = u128::from(bits) & ((1_u128 << width) - 1);
+    let is_negative = format.is_signed() && raw >> (width - 1) == 1;
+    let magnitude = if is_negative {
+        (1_u128 << width) - raw
+    } else {
+        raw
+    };
+
+    let frac_bits = format.frac_bits();
+    let frac_mask = (1_u128 << frac_bits) - 1;
+    let mut string = format!(
+        "{sign}{int}",
+        sign = if is_negative { "-" } else { "" },
+        int = magnitude >> frac_bits
+    );
+
+    // Every binary fraction has a finite decimal expansion. We get one digit
+    // per iteration by multiplying the remainder by ten. At most 64 fractional
+    // bits times ten always fit into an u128.
+    let mut remainder = magnitude & frac_mask;
+    if remainder != 0 {
+        string.push('.');
+    }
+    while remainder != 0 {
+        remainder *= 10;
+        string.push(char::from(b'0' + (remainder >> frac_bits) as u8));
+        remainder &= frac_mask;
+    }
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_point_to_decimal() {
+        let q15 = QFormat::from_input("q15").unwrap();
+        assert_eq!("0.5", fixed_point_to_decimal(q15, 0x4000));
+        assert_eq!("-1", fixed_point_to_decimal(q15, 0x8000));
+        assert_eq!("-0.000030517578125", fixed_point_to_decimal(q15, 0xffff));
+        let uq8_8 = QFormat::from_input("uq8.8").unwrap();
+        assert_eq!("255.99609375", fixed_point_to_decimal(uq8_8, 0xffff));
+        let uq32_32 = QFormat::from_input("uq32.32").unwrap();
+        assert_eq!("1.5", fixed_point_to_decimal(uq32_32, 0x1_8000_0000));
+        let uq64 = QFormat::from_input("uq64").unwrap();
+        assert_eq!("0.5", fixed_point_to_decimal(uq64, 1 << 63));
+    }
+}
@@ -0,0 +1,139 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for encodings of the Linux kernel: ioctl request numbers
+//! and device numbers (`dev_t`).
+
+use super::{Interpretation, OutputGroup, OutputLine, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use crate::parse::ioctl::{
+    IOC_DIRSHIFT, IOC_NRBITS, IOC_SIZEBITS, IOC_SIZESHIFT, IOC_TYPEBITS, IOC_TYPESHIFT,
+};
+
+pub(super) fn build_linux_kernel_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+    let request = bits as u32;
+
+    let lines = vec![
+        ("ioctl", format_ioctl_macro(request)),
+        ("ioctl fields", format_ioctl_fields(request)),
+        ("dev_t (old)", format_dev_t_old(bits)),
+        ("dev_t (new)", format_dev_t_new(bits)),
+        ("dev_t (kernel)", format_dev_t_kernel(bits)),
+    ];
+
+    OutputGroup {
+        title: Interpretation::LinuxKernel,
+        value_alignment: ValueAlignment::Left,
+        lines: lines
+            .into_iter()
+            .map(|(key, value)| OutputLine::new(key.to_string(), value))
+            .collect(),
+    }
+}
+
+/// Splits an ioctl request number into `(dir, type, nr, size)`.
+const fn ioctl_fields(request: u32) -> (u32, u32, u32, u32) {
+    (
+        request >> IOC_DIRSHIFT,
+        (request >> IOC_TYPESHIFT) & ((1 << IOC_TYPEBITS) - 1),
+        request & ((1 << IOC_NRBITS) - 1),
+        (request >> IOC_SIZESHIFT) & ((1 << IOC_SIZEBITS) - 1),
+    )
+}
+
+/// Formats the ioctl request number as the C macro that creates it, such as
+/// `_IOR('T', 1, 4)`.
+fn format_ioctl_macro(request: u32) -> String {
+    let (dir, type_, nr, size) = ioctl_fields(request);
+    let type_ = u8::try_from(type_)
+        .ok()
+        .filter(|c| c.is_ascii_graphic() && *c != b'\'' && *c != b'\\')
+        .map_or_else(
+            || format!("{type_:#04x}"),
+            |c| format!("'{}'", char::from(c)),
+        );
+    match dir {
+        0 if size == 0 => format!("_IO({type_}, {nr})"),
+        0 => format!("_IOC(_IOC_NONE, {type_}, {nr}, {size})"),
+        1 => format!("_IOW({type_}, {nr}, {size})"),
+        2 => format!("_IOR({type_}, {nr}, {size})"),
+        _ => format!("_IOWR({type_}, {nr}, {size})"),
+    }
+}
+
+fn format_ioctl_fields(request: u32) -> String {
+    let (dir, type_, nr, size) = ioctl_fields(request);
+    let dir = ["none", "write", "read", "read/write"][dir as usize];
+    format!("dir={dir} type={type_:#04x} nr={nr:#04x} size={size}")
+}
+
+/// The historic 16 bit `dev_t` with an 8 bit major and an 8 bit minor.
+fn format_dev_t_old(dev: u64) -> String {
+    if dev > 0xffff {
+        return "-".to_string();
+    }
+    format!("{}:{}", dev >> 8, dev & 0xff)
+}
+
+/// The 64 bit `dev_t` of glibc's `makedev()`, with a 32 bit major and a
+/// 32 bit minor.
+fn format_dev_t_new(dev: u64) -> String {
+    let major = ((dev >> 32) & 0xffff_f000) | ((dev >> 8) & 0xfff);
+    let minor = ((dev >> 12) & 0xffff_ff00) | (dev & 0xff);
+    format!("{major}:{minor}")
+}
+
+/// The kernel-internal 32 bit `dev_t` with a 12 bit major and a 20 bit minor.
+fn format_dev_t_kernel(dev: u64) -> String {
+    if dev > u64::from(u32::MAX) {
+        return "-".to_string();
+    }
+    format!("{}:{}", dev >> 20, dev & 0xf_ffff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ioctl() {
+        assert_eq!("_IO('T', 1)", format_ioctl_macro(0x5401));
+        assert_eq!("_IOR('T', 1, 4)", format_ioctl_macro(0x8004_5401));
+        assert_eq!("_IOWR(0x00, 255, 16383)", format_ioctl_macro(0xffff_00ff));
+        assert_eq!(
+            "dir=read type=0x54 nr=0x01 size=4",
+            format_ioctl_fields(0x8004_5401)
+        );
+    }
+
+    #[test]
+    fn test_format_dev_t() {
+        assert_eq!("8:1", format_dev_t_old(0x0801));
+        assert_eq!("-", format_dev_t_old(0x10000));
+        assert_eq!("8:1", format_dev_t_new(0x0801));
+        assert_eq!("259:65536", format_dev_t_new(0x1001_0300));
+        assert_eq!("8:1", format_dev_t_kernel(0x0080_0001));
+    }
+}
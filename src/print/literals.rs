@@ -0,0 +1,317 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group with ready-to-paste source code literals.
+
+use super::{
+    FormatOptions, Interpretation, OutputGroup, OutputLine, OutputOptions, Value, ValueAlignment,
+};
+use crate::parse::{Endianness, ParsedUserInput};
+use derive_more::Display;
+use regex::Regex;
+
+/// Regex for values that are integer literals in all supported languages,
+/// possibly with `_` as digit separator.
+pub const INTEGER_LITERAL_REGEX: &str =
+    "^-?(0x[0-9a-fA-F][0-9a-fA-F_]*|0b[01][01_]*|0o[0-7][0-7_]*|[0-9][0-9_]*)$";
+
+/// Regex for values that are float literals in all supported languages.
+pub const FLOAT_LITERAL_REGEX: &str = r"^-?[0-9]+\.[0-9]+(e[+-]?[0-9]+)?$";
+
+/// Programming languages for source code literals.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Display)]
+pub enum Language {
+    #[default]
+    Rust,
+    C,
+    Python,
+}
+
+impl Language {
+    /// All languages.
+    pub const ALL: [Self; 3] = [Self::Rust, Self::C, Self::Python];
+
+    /// Parses the [`Language`] from a normalized string, such as `python`.
+    pub fn from_input(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.to_string().to_lowercase() == name)
+    }
+
+    /// The next language, used to cycle through all languages.
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Rust => Self::C,
+            Self::C => Self::Python,
+            Self::Python => Self::Rust,
+        }
+    }
+
+    /// Formats a typed value of an output line as literal in this language.
+    /// Integers keep their numeral system, so that the `Bin` line becomes
+    /// `0b…` instead of a decimal literal of the same digits.
+    pub fn value_literal(self, value: &Value, options: &FormatOptions) -> String {
+        match value {
+            Value::Integer(integer) => self.literal(&integer.format_prefixed(options)),
+            value => self.literal(&value.format(options)),
+        }
+    }
+
+    /// Formats a value of an output line as literal in this language.
+    /// Numbers become number literals; everything else becomes a string
+    /// literal.
+    pub fn literal(self, value: &str) -> String {
+        if Regex::new(INTEGER_LITERAL_REGEX).unwrap().is_match(value) {
+            match self {
+                // C has neither digit separators nor the `0o` prefix
+                Self::C => value.replace('_', "").replacen("0o", "0", 1),
+                Self::Rust | Self::Python => value.to_owned(),
+            }
+        } else if Regex::new(FLOAT_LITERAL_REGEX).unwrap().is_match(value) {
+            value.to_owned()
+        } else {
+            let escaped = value
+                .chars()
+                .map(|c| match c {
+                    '\\' => "\\\\".to_owned(),
+                    '"' => "\\\"".to_owned(),
+                    '\n' => "\\n".to_owned(),
+                    '\t' => "\\t".to_owned(),
+                    c => c.to_string(),
+                })
+                .collect::<String>();
+            format!("\"{escaped}\"")
+        }
+    }
+}
+
+pub(super) fn build_literals_og(parsed: &ParsedUserInput, options: &OutputOptions) -> OutputGroup {
+    let width = effective_width(parsed, options);
+    let value = truncate_to_width(parsed.bits(), width);
+    let bytes = to_bytes(value, width, options.endianness);
+    let hex_bytes = bytes
+        .iter()
+        .map(|byte| format!("0x{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (from_bytes, struct_order) = match options.endianness {
+        Endianness::Little => ("from_le_bytes", '<'),
+        Endianness::Big => ("from_be_bytes", '>'),
+    };
+
+    let mut lines = Vec::new();
+    if let Some(info) = parsed.byte_sequence() {
+        lines.push(OutputLine::new(
+            "Input bytes".to_string(),
+            Value::Text(info.to_string()),
+        ));
+    }
+    lines.extend([
+        OutputLine::new(
+            "Rust".to_string(),
+            format!("{}_u{width}", format_rust_hex(value)),
+        ),
+        OutputLine::new(
+            "Rust (bytes)".to_string(),
+            format!("u{width}::{from_bytes}([{hex_bytes}])"),
+        ),
+    ]);
+    if width == 32 || width == 64 {
+        lines.push(OutputLine::new(
+            "Rust (float)".to_string(),
+            format!("f{width}::from_bits(0x{value:x})"),
+        ));
+    }
+    lines.extend([
+        OutputLine::new("C".to_string(), format!("0x{value:X}{}", c_suffix(width))),
+        OutputLine::new("C (bytes)".to_string(), format!("{{{hex_bytes}}}")),
+        OutputLine::new(
+            "Python".to_string(),
+            format!(
+                "struct.pack('{struct_order}{}', 0x{value:x})",
+                python_struct_format(width)
+            ),
+        ),
+    ]);
+
+    OutputGroup {
+        title: Interpretation::Literals,
+        value_alignment: ValueAlignment::Left,
+        lines,
+    }
+}
+
+/// Returns the width in bits that the literals are built for. This is either
+/// the width from the options, the width of a byte sequence input, or the
+/// smallest width that fits the value. Literals cover at most 64 bits.
+pub(super) fn effective_width(parsed: &ParsedUserInput, options: &OutputOptions) -> u32 {
+    options
+        .width
+        .or_else(|| parsed.byte_sequence().map(|info| info.width().min(64)))
+        .unwrap_or_else(|| {
+            [8, 16, 32]
+                .into_iter()
+                .find(|width| parsed.bits() >> width == 0)
+                .unwrap_or(64)
+        })
+}
+
+/// Returns the lower `width` bits of `value`.
+pub(super) const fn truncate_to_width(value: u64, width: u32) -> u64 {
+    if width >= 64 {
+        value
+    } else {
+        value & ((1 << width) - 1)
+    }
+}
+
+/// Returns the `width / 8` bytes of `value` in the given byte order.
+fn to_bytes(value: u64, width: u32, endianness: Endianness) -> Vec<u8> {
+    let len = width as usize / 8;
+    match endianness {
+        Endianness::Little => value.to_le_bytes()[..len].to_vec(),
+        Endianness::Big => value.to_be_bytes()[8 - len..].to_vec(),
+    }
+}
+
+/// Formats the value as Rust hex literal with an underscore between each
+/// group of four digits, such as `0xdead_beef`.
+fn format_rust_hex(value: u64) -> String {
+    let digits = format!("{value:x}");
+    let groups = digits
+        .as_bytes()
+        .rchunks(4)
+        .rev()
+        .map(|chunk| std::str::from_utf8(chunk).unwrap())
+        .collect::<Vec<_>>();
+    format!("0x{}", groups.join("_"))
+}
+
+/// Suffix for unsigned C integer literals, such as `ULL` for 64 bit values.
+const fn c_suffix(width: u32) -> &'static str {
+    match width {
+        8 | 16 => "",
+        32 => "U",
+        _ => "ULL",
+    }
+}
+
+/// Format character for Python's `struct` module.
+const fn python_struct_format(width: u32) -> char {
+    match width {
+        8 => 'B',
+        16 => 'H',
+        32 => 'I',
+        _ => 'Q',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{InputOptions, parse_input};
+
+    fn literals(input: &str, options: &OutputOptions) -> Vec<String> {
+        let parsed = parse_input(input, &InputOptions::default()).unwrap();
+        build_literals_og(&parsed, options)
+            .lines
+            .into_iter()
+            .map(|line| line.value.format(&Default::default()))
+            .collect()
+    }
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(
+            vec![
+                "0xdead_beef_u32",
+                "u32::from_le_bytes([0xef, 0xbe, 0xad, 0xde])",
+                "f32::from_bits(0xdeadbeef)",
+                "0xDEADBEEFU",
+                "{0xef, 0xbe, 0xad, 0xde}",
+                "struct.pack('<I', 0xdeadbeef)",
+            ],
+            literals("0xdeadbeef", &OutputOptions::default())
+        );
+
+        let options = OutputOptions {
+            endianness: Endianness::Big,
+            width: Some(16),
+            ..OutputOptions::default()
+        };
+        assert_eq!(
+            vec![
+                "0xffff_u16",
+                "u16::from_be_bytes([0xff, 0xff])",
+                "0xFFFF",
+                "{0xff, 0xff}",
+                "struct.pack('>H', 0xffff)",
+            ],
+            literals("-1", &options)
+        );
+
+        assert_eq!(
+            vec![
+                "4 bytes, Little Endian, 32 bit",
+                "0xdead_beef_u32",
+                "u32::from_le_bytes([0xef, 0xbe, 0xad, 0xde])",
+                "f32::from_bits(0xdeadbeef)",
+                "0xDEADBEEFU",
+                "{0xef, 0xbe, 0xad, 0xde}",
+                "struct.pack('<I', 0xdeadbeef)",
+            ],
+            literals("ef be ad de", &OutputOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_literal() {
+        assert_eq!("0x40a0_0000", Language::Rust.literal("0x40a0_0000"));
+        assert_eq!("0x40a00000", Language::C.literal("0x40a0_0000"));
+        assert_eq!("0o755", Language::Python.literal("0o755"));
+        assert_eq!("0755", Language::C.literal("0o755"));
+        assert_eq!("-0755", Language::C.literal("-0o755"));
+        assert_eq!("-128", Language::C.literal("-128"));
+        assert_eq!("0.5048828125", Language::Rust.literal("0.5048828125"));
+        assert_eq!(
+            "\"2004-05-10 22:19:44 UTC\"",
+            Language::Python.literal("2004-05-10 22:19:44 UTC")
+        );
+        assert_eq!(
+            r#""struct.pack('<I', 0x1)""#,
+            Language::Rust.literal("struct.pack('<I', 0x1)")
+        );
+        assert_eq!(r#""a\"b\\c""#, Language::C.literal("a\"b\\c"));
+        assert_eq!(Some(Language::Python), Language::from_input("python"));
+        assert_eq!(None, Language::from_input("go"));
+    }
+
+    #[test]
+    fn test_format_rust_hex() {
+        assert_eq!("0x0", format_rust_hex(0));
+        assert_eq!("0xabc", format_rust_hex(0xabc));
+        assert_eq!("0x1_0000", format_rust_hex(0x10000));
+        assert_eq!("0xdead_beef", format_rust_hex(0xdead_beef));
+    }
+}
@@ -22,14 +22,61 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+mod colors;
+mod encodings;
+mod fixed_point;
+mod linux_kernel;
+mod literals;
+mod network;
+mod os_errors;
+mod permissions;
+mod timestamps;
+mod value;
+mod varints;
+
 use crate::parse::unit::Unit;
-use crate::parse::{NumeralSystem, ParsedUserInput};
+use crate::parse::{Endianness, NumeralSystem, ParsedUserInput, QFormat};
+use colors::build_colors_og;
+pub use colors::{Rgba, color_swatches};
 use derive_more::Display;
-use fraction_list_fmt_align::{FormatPrecision, FractionNumber, fmt_align_fractions};
-
-const MAX_PRECISION: u8 = 4;
+use encodings::build_encodings_og;
+use fixed_point::build_fixed_point_og;
+use linux_kernel::build_linux_kernel_og;
+pub use literals::Language;
+use literals::build_literals_og;
+use network::build_network_og;
+use os_errors::build_os_errors_og;
+use permissions::build_permissions_og;
+use timestamps::build_timestamps_og;
+use value::format_values;
+pub use value::{Date, Float, FloatFormat, FormatOptions, Integer, Precision, Size, Value};
+use varints::build_varints_og;
+
+/// Default number of fraction digits for floats and sizes.
+pub const DEFAULT_PRECISION: u8 = 4;
+
+/// Largest supported number of fraction digits for floats and sizes.
+pub const MAX_PRECISION: u8 = 20;
+
+/// Options from the command line and the config file that influence the
+/// content of the output groups. How values are formatted is decided at
+/// render time, see [`FormatOptions`].
+#[derive(Debug, Clone, Default)]
+pub struct OutputOptions {
+    /// Fixed-point formats to show in addition to [`QFormat::COMMON`].
+    pub q_formats: Vec<QFormat>,
+    /// Byte order for byte-wise representations, such as literals.
+    pub endianness: Endianness,
+    /// Width in bits for width-dependent representations, such as literals.
+    /// If `None`, the width is derived from the input.
+    pub width: Option<u32>,
+}
 
-pub fn get_output_group(parsed: &ParsedUserInput, representation: Interpretation) -> OutputGroup {
+pub fn get_output_group(
+    parsed: &ParsedUserInput,
+    representation: Interpretation,
+    options: &OutputOptions,
+) -> OutputGroup {
     match representation {
         Interpretation::NumeralSystems => build_numeral_systems_og(parsed),
         Interpretation::Bit64BigEndian => build_bits_og(parsed),
@@ -38,76 +85,94 @@ pub fn get_output_group(parsed: &ParsedUserInput, representation: Interpretation
         Interpretation::IEEE754 => build_ieee754_og(parsed),
         Interpretation::Bytes => build_bytes_og(parsed),
         Interpretation::Ibibytes => build_ibi_bytes_og(parsed),
+        Interpretation::FixedPoint => build_fixed_point_og(parsed, options),
+        Interpretation::Literals => build_literals_og(parsed, options),
+        Interpretation::Timestamps => build_timestamps_og(parsed),
+        Interpretation::Network => build_network_og(parsed),
+        Interpretation::OsErrors => build_os_errors_og(parsed),
+        Interpretation::LinuxKernel => build_linux_kernel_og(parsed),
+        Interpretation::Permissions => build_permissions_og(parsed),
+        Interpretation::Colors => build_colors_og(parsed),
+        Interpretation::AlternativeEncodings => build_encodings_og(parsed),
+        Interpretation::Varints => build_varints_og(parsed),
     }
 }
 
 fn build_numeral_systems_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let magnitude = i128::from(parsed.value());
+    let value = if parsed.sign().is_neg() {
+        -magnitude
+    } else {
+        magnitude
+    };
+    let line = |radix: NumeralSystem| {
+        let integer = Integer {
+            value,
+            width: 64,
+            signed: parsed.sign().is_neg(),
+            radix,
+            padded: false,
+            grouped: None,
+        };
+        OutputLine::new(radix.to_string(), integer)
+    };
     OutputGroup {
         title: Interpretation::NumeralSystems,
         value_alignment: ValueAlignment::Right,
         lines: vec![
-            OutputLine {
-                key: format!("{}", NumeralSystem::Decimal),
-                value: format!("{}{}", parsed.sign(), parsed.value()),
-            },
-            OutputLine {
-                key: format!("{}", NumeralSystem::Bin),
-                value: format!("{}{:b}", parsed.sign(), parsed.value()),
-            },
-            OutputLine {
-                key: format!("{}", NumeralSystem::Octal),
-                value: format!("{}{:o}", parsed.sign(), parsed.value()),
-            },
-            OutputLine {
-                key: format!("{}", NumeralSystem::Hex),
-                value: format!("{}{:x}", parsed.sign(), parsed.value()),
-            },
+            line(NumeralSystem::Decimal),
+            line(NumeralSystem::Bin),
+            line(NumeralSystem::Octal),
+            line(NumeralSystem::Hex),
         ],
     }
 }
 
 fn build_bits_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let line = |key: &str, radix: NumeralSystem, grouped: bool| {
+        let integer = Integer {
+            value: i128::from(parsed.bits()),
+            width: 64,
+            signed: false,
+            radix,
+            padded: true,
+            grouped: Some(grouped),
+        };
+        OutputLine::new(key.to_string(), integer)
+    };
     OutputGroup {
         title: Interpretation::Bit64BigEndian,
         value_alignment: ValueAlignment::Left,
         lines: vec![
-            OutputLine {
-                key: "Bin (Rust-style)".to_string(),
-                value: format!("0b{}", format_64bit_bin_rust_style(parsed.value())),
-            },
-            OutputLine {
-                key: "Bin (C-style)".to_string(),
-                value: format!("0b{:064b}", parsed.value()),
-            },
-            OutputLine {
-                key: "Hex".to_string(),
-                value: format!("0x{:016x}", parsed.value()),
-            },
+            line("Bin (Rust-style)", NumeralSystem::Bin, true),
+            line("Bin (C-style)", NumeralSystem::Bin, false),
+            line("Hex", NumeralSystem::Hex, false),
         ],
     }
 }
 
 fn build_signed_integers_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
     OutputGroup {
         title: Interpretation::SignedIntegers,
         value_alignment: ValueAlignment::Right,
         lines: vec![
-            OutputLine {
-                key: " i8".to_string(),
-                value: format!("{}{}", parsed.sign(), parsed.value() as i8),
-            },
-            OutputLine {
-                key: "i16".to_string(),
-                value: format!("{}{}", parsed.sign(), parsed.value() as i16),
-            },
-            OutputLine {
-                key: "i32".to_string(),
-                value: format!("{}{}", parsed.sign(), parsed.value() as i32),
-            },
-            OutputLine {
-                key: "i64".to_string(),
-                value: format!("{}{}", parsed.sign(), parsed.value() as i64),
-            },
+            OutputLine::new(
+                " i8".to_string(),
+                Integer::decimal(i128::from(bits as i8), 8, true),
+            ),
+            OutputLine::new(
+                "i16".to_string(),
+                Integer::decimal(i128::from(bits as i16), 16, true),
+            ),
+            OutputLine::new(
+                "i32".to_string(),
+                Integer::decimal(i128::from(bits as i32), 32, true),
+            ),
+            OutputLine::new(
+                "i64".to_string(),
+                Integer::decimal(i128::from(bits as i64), 64, true),
+            ),
         ],
     }
 }
@@ -117,141 +182,100 @@ fn build_unsigned_integers_og(parsed: &ParsedUserInput) -> OutputGroup {
         title: Interpretation::UnsignedIntegers,
         value_alignment: ValueAlignment::Right,
         lines: vec![
-            OutputLine {
-                key: " u8".to_string(),
-                value: format!("{}", parsed.value() as u8),
-            },
-            OutputLine {
-                key: "u16".to_string(),
-                value: format!("{}", parsed.value() as u16),
-            },
-            OutputLine {
-                key: "u32".to_string(),
-                value: format!("{}", parsed.value() as u32),
-            },
-            OutputLine {
-                key: "u64".to_string(),
-                value: format!("{}", parsed.value()),
-            },
+            OutputLine::new(
+                " u8".to_string(),
+                Integer::decimal(i128::from(parsed.bits() as u8), 8, false),
+            ),
+            OutputLine::new(
+                "u16".to_string(),
+                Integer::decimal(i128::from(parsed.bits() as u16), 16, false),
+            ),
+            OutputLine::new(
+                "u32".to_string(),
+                Integer::decimal(i128::from(parsed.bits() as u32), 32, false),
+            ),
+            OutputLine::new(
+                "u64".to_string(),
+                Integer::decimal(i128::from(parsed.bits()), 64, false),
+            ),
         ],
     }
 }
 
 fn build_ieee754_og(parsed: &ParsedUserInput) -> OutputGroup {
-    // maximum 15 digits fractional precision
-    // also rounds the number at the 15'th place/digit
-    let f32_num = f32::from_ne_bytes((parsed.value() as i32).to_ne_bytes());
-    let f64_num = f64::from_ne_bytes(parsed.value().to_ne_bytes());
-    let fmt_vec = fmt_align_fractions(
-        &[FractionNumber::F32(f32_num), FractionNumber::F64(f64_num)],
-        FormatPrecision::Max(MAX_PRECISION),
-    );
+    let f32_num = f32::from_bits(parsed.bits() as u32);
+    let f64_num = f64::from_bits(parsed.bits());
     OutputGroup {
         title: Interpretation::IEEE754,
         // not important here if left or right because the formatting
         // utility already makes sure that all values are same length (via spaces)
         value_alignment: ValueAlignment::Left,
         lines: vec![
-            OutputLine {
-                key: "f32".to_string(),
-                // value: format!("'{}'", f32_fmt),
-                value: fmt_vec[0].as_str().to_string(),
-            },
-            OutputLine {
-                key: "f64".to_string(),
-                // value: format!("'{}'", f64_fmt),
-                value: fmt_vec[1].as_str().to_string(),
-            },
+            OutputLine::new("f32".to_string(), Float::f32(f32_num)),
+            OutputLine::new("f64".to_string(), Float::f64(f64_num)),
         ],
     }
 }
 
-fn build_bytes_og(parsed: &ParsedUserInput) -> OutputGroup {
-    let base_value_f64 = parsed.value() as f64;
-    let fmt_vec = fmt_align_fractions(
-        &[
-            FractionNumber::F64(base_value_f64),
-            FractionNumber::F64(Unit::base_to_target(Unit::Kilo, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Mega, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Giga, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Tera, base_value_f64)),
-        ],
-        FormatPrecision::Max(MAX_PRECISION),
+/// Builds a group with the value in bytes, followed by the value in each
+/// of the `units`. Sizes of negative inputs are their magnitude, such as
+/// `16 MiB` for `-16mib`.
+fn build_sizes_og(
+    parsed: &ParsedUserInput,
+    title: Interpretation,
+    base_key: &str,
+    units: [(Unit, &str); 4],
+) -> OutputGroup {
+    let size = |unit: Unit| Size {
+        bytes: parsed.value(),
+        unit: unit.bytes(),
+    };
+    let mut lines = vec![OutputLine::new(base_key.to_string(), size(Unit::Base))];
+    lines.extend(
+        units
+            .iter()
+            .map(|(unit, key)| OutputLine::new(key.to_string(), size(*unit))),
     );
     OutputGroup {
-        title: Interpretation::Bytes,
+        title,
         // not important here if left or right because the formatting
         // utility already makes sure that all values are same length (via spaces)
         value_alignment: ValueAlignment::Left,
-        lines: vec![
-            OutputLine {
-                key: " B".to_string(),
-                value: fmt_vec[0].to_string(),
-            },
-            OutputLine {
-                key: "KB".to_string(),
-                value: fmt_vec[1].to_string(),
-            },
-            OutputLine {
-                key: "MB".to_string(),
-                value: fmt_vec[2].to_string(),
-            },
-            OutputLine {
-                key: "GB".to_string(),
-                value: fmt_vec[3].to_string(),
-            },
-            OutputLine {
-                key: "TB".to_string(),
-                value: fmt_vec[4].to_string(),
-            },
-        ],
+        lines,
     }
 }
 
-fn build_ibi_bytes_og(parsed: &ParsedUserInput) -> OutputGroup {
-    let base_value_f64 = parsed.value() as f64;
-    let fmt_vec = fmt_align_fractions(
-        &[
-            FractionNumber::F64(base_value_f64),
-            FractionNumber::F64(Unit::base_to_target(Unit::Kibi, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Mibi, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Gibi, base_value_f64)),
-            FractionNumber::F64(Unit::base_to_target(Unit::Tebi, base_value_f64)),
+fn build_bytes_og(parsed: &ParsedUserInput) -> OutputGroup {
+    build_sizes_og(
+        parsed,
+        Interpretation::Bytes,
+        " B",
+        [
+            (Unit::Kilo, "KB"),
+            (Unit::Mega, "MB"),
+            (Unit::Giga, "GB"),
+            (Unit::Tera, "TB"),
         ],
-        FormatPrecision::Max(MAX_PRECISION),
-    );
-    OutputGroup {
-        title: Interpretation::Ibibytes,
-        // because they are already aligned Left and not right
-        value_alignment: ValueAlignment::Left,
-        lines: vec![
-            OutputLine {
-                key: " iB".to_string(),
-                value: fmt_vec[0].to_string(),
-            },
-            OutputLine {
-                key: "KiB".to_string(),
-                value: fmt_vec[1].to_string(),
-            },
-            OutputLine {
-                key: "MiB".to_string(),
-                value: fmt_vec[2].to_string(),
-            },
-            OutputLine {
-                key: "GiB".to_string(),
-                value: fmt_vec[3].to_string(),
-            },
-            OutputLine {
-                key: "TiB".to_string(),
-                value: fmt_vec[4].to_string(),
-            },
+    )
+}
+
+fn build_ibi_bytes_og(parsed: &ParsedUserInput) -> OutputGroup {
+    build_sizes_og(
+        parsed,
+        Interpretation::Ibibytes,
+        " iB",
+        [
+            (Unit::Kibi, "KiB"),
+            (Unit::Mibi, "MiB"),
+            (Unit::Gibi, "GiB"),
+            (Unit::Tebi, "TiB"),
         ],
-    }
+    )
 }
 
 /// Describes the kind of an output group that is dedicated to
 /// a specific class of interpretations.
-#[derive(Debug, Display, Copy, Clone)]
+#[derive(Debug, Display, Copy, Clone, PartialEq, Eq)]
 pub enum Interpretation {
     #[display("Numeral Systems")]
     NumeralSystems,
@@ -267,6 +291,79 @@ pub enum Interpretation {
     Bytes,
     #[display("Size in *ebi/*ibi Bytes")]
     Ibibytes,
+    #[display("Fixed-Point (Q Format)")]
+    FixedPoint,
+    #[display("Source Code Literals")]
+    Literals,
+    #[display("Timestamps (UTC)")]
+    Timestamps,
+    #[display("Network Addresses")]
+    Network,
+    #[display("OS Errors and Statuses")]
+    OsErrors,
+    #[display("Linux Kernel (ioctl, dev_t)")]
+    LinuxKernel,
+    #[display("Permissions and Flags")]
+    Permissions,
+    #[display("Colors")]
+    Colors,
+    #[display("Alternative Integer Encodings")]
+    AlternativeEncodings,
+    #[display("Variable-Length Integers")]
+    Varints,
+}
+
+impl Interpretation {
+    /// All interpretations, in the default order of the TUI.
+    pub const ALL: [Self; 17] = [
+        Self::NumeralSystems,
+        Self::IEEE754,
+        Self::Bytes,
+        Self::Ibibytes,
+        Self::SignedIntegers,
+        Self::UnsignedIntegers,
+        Self::Bit64BigEndian,
+        Self::FixedPoint,
+        Self::Timestamps,
+        Self::Literals,
+        Self::Network,
+        Self::OsErrors,
+        Self::LinuxKernel,
+        Self::Permissions,
+        Self::Colors,
+        Self::AlternativeEncodings,
+        Self::Varints,
+    ];
+
+    /// Stable identifier, as used in the config file.
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::NumeralSystems => "numeral-systems",
+            Self::Bit64BigEndian => "bits",
+            Self::SignedIntegers => "signed-integers",
+            Self::UnsignedIntegers => "unsigned-integers",
+            Self::IEEE754 => "ieee754",
+            Self::Bytes => "bytes",
+            Self::Ibibytes => "ibibytes",
+            Self::FixedPoint => "fixed-point",
+            Self::Literals => "literals",
+            Self::Timestamps => "timestamps",
+            Self::Network => "network",
+            Self::OsErrors => "os-errors",
+            Self::LinuxKernel => "linux-kernel",
+            Self::Permissions => "permissions",
+            Self::Colors => "colors",
+            Self::AlternativeEncodings => "encodings",
+            Self::Varints => "varints",
+        }
+    }
+
+    /// Parses the identifier from [`Self::id`].
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|interpretation| interpretation.id() == id)
+    }
 }
 
 /// Alignment of the value against the other values
@@ -300,21 +397,55 @@ pub struct OutputGroup {
 }
 
 impl OutputGroup {
-    fn find_longest_value_string(&self) -> usize {
-        self.lines.iter().map(|i| i.value().len()).max().unwrap()
+    pub const fn title(&self) -> Interpretation {
+        self.title
     }
 
-    fn find_longest_key_string(&self) -> usize {
-        self.lines.iter().map(|i| i.key().len()).max().unwrap()
+    /// The width of the widest line, i.e., the padded key and value with
+    /// `": "` in between, or of the title.
+    pub fn text_width(&self, options: &FormatOptions) -> usize {
+        self.iter(options)
+            .map(|(key, value)| key.chars().count() + 2 + value.chars().count())
+            .chain(std::iter::once(self.title.to_string().chars().count()))
+            .max()
+            .unwrap_or(0)
     }
 
-    pub const fn title(&self) -> Interpretation {
-        self.title
+    /// Getter for `lines`. The values of the lines are not formatted.
+    pub fn lines(&self) -> &[OutputLine] {
+        &self.lines
+    }
+
+    /// The number of lines, without the title.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The first id that more than one line has, if any. Ids must be
+    /// unique, so this is an internal error.
+    pub fn duplicate_line_id(&self) -> Option<&str> {
+        self.lines.iter().enumerate().find_map(|(index, line)| {
+            self.lines[..index]
+                .iter()
+                .any(|other| other.id == line.id)
+                .then_some(line.id())
+        })
+    }
+
+    /// A group with the given lines, for tests of the renderers.
+    #[cfg(test)]
+    pub(crate) const fn from_lines(title: Interpretation, lines: Vec<OutputLine>) -> Self {
+        Self {
+            title,
+            lines,
+            value_alignment: ValueAlignment::Left,
+        }
     }
 
-    /// Returns an iterator of type [`OutputGroupIterator`].
-    pub const fn iter(&self) -> OutputGroupIterator<'_> {
-        OutputGroupIterator::new(self)
+    /// Returns an iterator of type [`OutputGroupIterator`] that formats the
+    /// values with the given options.
+    pub fn iter(&self, options: &FormatOptions) -> OutputGroupIterator<'_> {
+        OutputGroupIterator::new(self, options)
     }
 }
 
@@ -325,12 +456,19 @@ impl OutputGroup {
 /// API users to add `": "` or similar after each key.
 pub struct OutputGroupIterator<'a> {
     og: &'a OutputGroup,
+    /// The formatted values of all lines.
+    values: Vec<String>,
     line: usize,
 }
 
 impl<'a> OutputGroupIterator<'a> {
-    const fn new(og: &'a OutputGroup) -> Self {
-        OutputGroupIterator { og, line: 0 }
+    fn new(og: &'a OutputGroup, options: &FormatOptions) -> Self {
+        let values = og.lines.iter().map(OutputLine::value).collect::<Vec<_>>();
+        OutputGroupIterator {
+            og,
+            values: format_values(&values, options),
+            line: 0,
+        }
     }
 }
 
@@ -341,22 +479,24 @@ impl Iterator for OutputGroupIterator<'_> {
         if self.line >= self.og.lines.len() {
             None
         } else {
-            let longest_key = self.og.find_longest_key_string();
-            let longest_value = self.og.find_longest_value_string();
+            let length = |text: &str| text.chars().count();
+            let longest_key = self.og.lines.iter().map(|i| length(i.key())).max().unwrap();
+            let longest_value = self.values.iter().map(|i| length(i)).max().unwrap();
 
             let line = &self.og.lines[self.line];
+            let formatted_value = &self.values[self.line];
 
             // additional spaces between key and value
             let additional_spaces = if self.og.value_alignment == ValueAlignment::Left {
-                longest_key - line.key.len()
+                longest_key - length(&line.key)
             } else {
-                longest_key - line.key.len() + longest_value - line.value.len()
+                longest_key - length(&line.key) + longest_value - length(formatted_value)
             };
 
             let value = format!(
                 "{additional_spaces}{value}",
                 additional_spaces = " ".repeat(additional_spaces),
-                value = line.value,
+                value = formatted_value,
             );
 
             self.line += 1;
@@ -366,46 +506,50 @@ impl Iterator for OutputGroupIterator<'_> {
     }
 }
 
-/// Bundles the key and the value for a line inside a [`OutputGroup`].
+/// Bundles the key and the typed value for a line inside a
+/// [`OutputGroup`].
 #[derive(Clone, Debug)]
 pub struct OutputLine {
+    /// Stable identifier of the line, unique within its group. Usually
+    /// derived from the key, such as `bin-0b` for `Bin (0b)`.
+    id: String,
     key: String,
-    value: String,
+    value: Value,
 }
 
 impl OutputLine {
+    fn new(key: String, value: impl Into<Value>) -> Self {
+        Self::with_id(line_id(&key), key, value)
+    }
+
+    /// A line with an explicit id, for keys that only differ in characters
+    /// that [`line_id`] drops, such as `errno` and `-errno`.
+    pub(crate) fn with_id(id: impl Into<String>, key: String, value: impl Into<Value>) -> Self {
+        Self {
+            id: id.into(),
+            key,
+            value: value.into(),
+        }
+    }
+    pub fn id(&self) -> &str {
+        &self.id
+    }
     pub fn key(&self) -> &str {
         &self.key
     }
-    pub fn value(&self) -> &str {
+    pub const fn value(&self) -> &Value {
         &self.value
     }
 }
 
-/// Transforms for example "1111000010101010" to "11110000_10101010" to
-/// increase readability, but with a 64 bit integer.
-fn format_64bit_bin_rust_style(number: u64) -> String {
-    let string_fixed_len = format!("{:064b}", number);
-    format_num_add_delimiters(&string_fixed_len, 8)
-}
-
-/// Transforms for example "1111000010101010" to "11110000_10101010" to
-/// increase readability.
-fn format_num_add_delimiters(digits: &str, chunksize: usize) -> String {
-    let chars = digits.chars().collect::<Vec<char>>();
-    assert_eq!(chars.len() % chunksize, 0);
-    let formatted_with_delimiters = chars
-        .chunks(chunksize)
-        .map(|chars| chars.iter().collect::<String>())
-        .fold(String::new(), |combined: String, group| {
-            format!("{}_{}", combined, group)
-        });
-
-    // transform _00000000_00000000 to 00000000_00000000 (remove leading underscore)
-    formatted_with_delimiters
-        .chars()
-        .skip(1) // skip first item
-        .collect::<String>()
+/// Derives the identifier of a line from its key: lowercase, with `-`
+/// instead of any other characters than letters and digits.
+fn line_id(key: &str) -> String {
+    key.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
 }
 
 #[cfg(test)]
@@ -425,14 +569,8 @@ mod tests {
         OutputGroup {
             title: Interpretation::NumeralSystems,
             lines: vec![
-                OutputLine {
-                    key: "foo".to_string(),
-                    value: "foobar".to_string(),
-                },
-                OutputLine {
-                    key: "foo2".to_string(),
-                    value: "foobar2".to_string(),
-                },
+                OutputLine::new("foo".to_string(), "foobar".to_string()),
+                OutputLine::new("foo2".to_string(), "foobar2".to_string()),
             ],
             value_alignment: ValueAlignment::Left,
         }
@@ -443,7 +581,7 @@ mod tests {
         let mut og = get_output_base_group();
         og.value_alignment = ValueAlignment::Left;
 
-        let final_str = fold_og_iter_to_string(og.iter());
+        let final_str = fold_og_iter_to_string(og.iter(&FormatOptions::default()));
 
         // println!("{}", final_str);
         assert_eq!(
@@ -460,7 +598,7 @@ mod tests {
         let mut og = get_output_base_group();
         og.value_alignment = ValueAlignment::Right;
 
-        let final_str = fold_og_iter_to_string(og.iter());
+        let final_str = fold_og_iter_to_string(og.iter(&FormatOptions::default()));
 
         // println!("{}", final_str);
         assert_eq!(
@@ -471,4 +609,89 @@ mod tests {
             final_str
         );
     }
+
+    #[test]
+    fn test_line_id() {
+        assert_eq!("bin-0b", line_id("Bin (0b)"));
+        assert_eq!("i8", line_id(" i8"));
+        assert_eq!("sign-mag-i16", line_id("sign-mag i16"));
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let parsed = crate::parse::parse_input("-200", &Default::default()).unwrap();
+        let og = get_output_group(&parsed, Interpretation::SignedIntegers, &Default::default());
+        assert_eq!(
+            &Value::Integer(Integer::decimal(56, 8, true)),
+            og.lines()[0].value()
+        );
+        assert_eq!("i8", og.lines()[0].id());
+        let og = get_output_group(&parsed, Interpretation::NumeralSystems, &Default::default());
+        assert!(matches!(
+            og.lines()[0].value(),
+            Value::Integer(Integer { value: -200, .. })
+        ));
+    }
+
+    #[test]
+    fn test_negative_input_bits() {
+        // all groups but the numeral systems and the sizes interpret the
+        // two's complement
+        let parsed = crate::parse::parse_input("-7", &Default::default()).unwrap();
+        let bits = i128::from(u64::MAX - 6);
+        let og = get_output_group(
+            &parsed,
+            Interpretation::UnsignedIntegers,
+            &Default::default(),
+        );
+        assert_eq!(
+            &Value::Integer(Integer::decimal(bits, 64, false)),
+            og.lines()[3].value()
+        );
+        let og = get_output_group(&parsed, Interpretation::Bit64BigEndian, &Default::default());
+        assert!(matches!(
+            og.lines()[0].value(),
+            Value::Integer(Integer { value, .. }) if *value == bits
+        ));
+        let og = get_output_group(&parsed, Interpretation::Bytes, &Default::default());
+        assert!(matches!(
+            og.lines()[0].value(),
+            Value::Size(Size { bytes: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn test_unique_line_ids() {
+        let options = OutputOptions {
+            q_formats: vec![QFormat::from_input("q15").unwrap()],
+            ..OutputOptions::default()
+        };
+        for input in ["-2", "fe80::1", "de ad be ef", "0.5q15"] {
+            let parsed = crate::parse::parse_input(input, &Default::default()).unwrap();
+            for interpretation in Interpretation::ALL {
+                let og = get_output_group(&parsed, interpretation, &options);
+                assert_eq!(None, og.duplicate_line_id(), "{input}: {interpretation}");
+            }
+        }
+
+        let og = OutputGroup::from_lines(
+            Interpretation::OsErrors,
+            vec![
+                OutputLine::with_id("errno", "errno".to_string(), "-".to_string()),
+                OutputLine::with_id("errno", "-errno".to_string(), "-".to_string()),
+            ],
+        );
+        assert_eq!(Some("errno"), og.duplicate_line_id());
+    }
+
+    #[test]
+    fn test_interpretation_id() {
+        for interpretation in Interpretation::ALL {
+            assert_eq!(
+                Some(interpretation),
+                Interpretation::from_id(interpretation.id())
+            );
+        }
+        assert_eq!(None, Interpretation::from_id("foo"));
+    }
 }
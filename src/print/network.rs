@@ -0,0 +1,159 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for network address interpretations.
+
+use super::{Interpretation, OutputGroup, OutputLine, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Well-known TCP/UDP ports and their services.
+const WELL_KNOWN_PORTS: [(u16, &str); 20] = [
+    (20, "ftp-data"),
+    (21, "ftp"),
+    (22, "ssh"),
+    (23, "telnet"),
+    (25, "smtp"),
+    (53, "dns"),
+    (67, "dhcp"),
+    (80, "http"),
+    (110, "pop3"),
+    (123, "ntp"),
+    (143, "imap"),
+    (161, "snmp"),
+    (443, "https"),
+    (514, "syslog"),
+    (993, "imaps"),
+    (1883, "mqtt"),
+    (3306, "mysql"),
+    (5432, "postgresql"),
+    (6379, "redis"),
+    (8080, "http-alt"),
+];
+
+pub(super) fn build_network_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+    let ipv4 = bits as u32;
+    let port = bits as u16;
+    let mac = bits & 0xffff_ffff_ffff;
+
+    let mut lines = vec![
+        OutputLine::new(
+            "IPv4 (network)".to_string(),
+            Ipv4Addr::from(ipv4).to_string(),
+        ),
+        OutputLine::new(
+            "IPv4 (LE host)".to_string(),
+            Ipv4Addr::from(ipv4.swap_bytes()).to_string(),
+        ),
+    ];
+    if let Some(wide_bits) = parsed.wide_bits() {
+        lines.push(OutputLine::new(
+            "IPv6".to_string(),
+            Ipv6Addr::from(wide_bits).to_string(),
+        ));
+    }
+    lines.extend([
+        OutputLine::new("MAC (EUI-48)".to_string(), format_mac(mac)),
+        OutputLine::new("MAC flags".to_string(), format_mac_flags(mac)),
+        OutputLine::new("Port (network)".to_string(), format_port(port)),
+        OutputLine::new("Port (LE host)".to_string(), format_port(port.swap_bytes())),
+    ]);
+
+    OutputGroup {
+        title: Interpretation::Network,
+        value_alignment: ValueAlignment::Left,
+        lines,
+    }
+}
+
+/// Formats the lower 48 bits as MAC address, such as `00:1a:2b:3c:4d:5e`.
+fn format_mac(mac: u64) -> String {
+    mac.to_be_bytes()[2..]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Describes the OUI (first three octets) and the flags of the first octet
+/// of a MAC address: the U/L bit (locally administered) and the I/G bit
+/// (multicast).
+fn format_mac_flags(mac: u64) -> String {
+    let first_octet = (mac >> 40) as u8;
+    format!(
+        "OUI {}, {}, {}",
+        &format_mac(mac)[..8],
+        if first_octet & 0b10 == 0 {
+            "universal"
+        } else {
+            "local"
+        },
+        if first_octet & 0b01 == 0 {
+            "unicast"
+        } else {
+            "multicast"
+        }
+    )
+}
+
+/// Formats a port and adds the name of well-known services.
+fn format_port(port: u16) -> String {
+    WELL_KNOWN_PORTS
+        .iter()
+        .find(|(well_known_port, _)| *well_known_port == port)
+        .map_or_else(|| port.to_string(), |(_, name)| format!("{port} ({name})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+
+    fn network_lines(input: &str) -> Vec<(String, String)> {
+        let parsed = parse_input(input, &Default::default()).unwrap();
+        build_network_og(&parsed)
+            .lines
+            .into_iter()
+            .map(|line| (line.key, line.value.format(&Default::default())))
+            .collect()
+    }
+
+    #[test]
+    fn test_network_og() {
+        let lines = network_lines("0xc0a80101");
+        assert_eq!("192.168.1.1", lines[0].1);
+        assert_eq!("1.1.168.192", lines[1].1);
+        assert_eq!("00:00:c0:a8:01:01", lines[2].1);
+        assert_eq!("OUI 00:00:c0, universal, unicast", lines[3].1);
+        assert_eq!("257", lines[4].1);
+
+        let lines = network_lines("fe80::1");
+        assert_eq!(("IPv6".to_string(), "fe80::1".to_string()), lines[2]);
+
+        let lines = network_lines("0x0303_0000_01bb");
+        assert_eq!("OUI 03:03:00, local, multicast", lines[3].1);
+        assert_eq!("443 (https)", lines[4].1);
+    }
+}
@@ -0,0 +1,401 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for OS error codes and process statuses. All tables are
+//! embedded and describe Linux (generic/x86 numbering), independent of the
+//! host's libc.
+
+use super::{Interpretation, OutputGroup, OutputLine, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use crate::parse::flags::signal_name;
+
+/// Largest error number that the Linux kernel encodes in pointers, see
+/// `MAX_ERRNO` in `include/linux/err.h`.
+const MAX_ERRNO: u64 = 4095;
+
+/// Linux error numbers with their symbolic name and message.
+const ERRNO_TABLE: &[(u16, &str, &str)] = &[
+    (1, "EPERM", "Operation not permitted"),
+    (2, "ENOENT", "No such file or directory"),
+    (3, "ESRCH", "No such process"),
+    (4, "EINTR", "Interrupted system call"),
+    (5, "EIO", "Input/output error"),
+    (6, "ENXIO", "No such device or address"),
+    (7, "E2BIG", "Argument list too long"),
+    (8, "ENOEXEC", "Exec format error"),
+    (9, "EBADF", "Bad file descriptor"),
+    (10, "ECHILD", "No child processes"),
+    (11, "EAGAIN", "Resource temporarily unavailable"),
+    (12, "ENOMEM", "Cannot allocate memory"),
+    (13, "EACCES", "Permission denied"),
+    (14, "EFAULT", "Bad address"),
+    (15, "ENOTBLK", "Block device required"),
+    (16, "EBUSY", "Device or resource busy"),
+    (17, "EEXIST", "File exists"),
+    (18, "EXDEV", "Invalid cross-device link"),
+    (19, "ENODEV", "No such device"),
+    (20, "ENOTDIR", "Not a directory"),
+    (21, "EISDIR", "Is a directory"),
+    (22, "EINVAL", "Invalid argument"),
+    (23, "ENFILE", "Too many open files in system"),
+    (24, "EMFILE", "Too many open files"),
+    (25, "ENOTTY", "Inappropriate ioctl for device"),
+    (26, "ETXTBSY", "Text file busy"),
+    (27, "EFBIG", "File too large"),
+    (28, "ENOSPC", "No space left on device"),
+    (29, "ESPIPE", "Illegal seek"),
+    (30, "EROFS", "Read-only file system"),
+    (31, "EMLINK", "Too many links"),
+    (32, "EPIPE", "Broken pipe"),
+    (33, "EDOM", "Numerical argument out of domain"),
+    (34, "ERANGE", "Numerical result out of range"),
+    (35, "EDEADLK", "Resource deadlock avoided"),
+    (36, "ENAMETOOLONG", "File name too long"),
+    (37, "ENOLCK", "No locks available"),
+    (38, "ENOSYS", "Function not implemented"),
+    (39, "ENOTEMPTY", "Directory not empty"),
+    (40, "ELOOP", "Too many levels of symbolic links"),
+    (42, "ENOMSG", "No message of desired type"),
+    (43, "EIDRM", "Identifier removed"),
+    (44, "ECHRNG", "Channel number out of range"),
+    (45, "EL2NSYNC", "Level 2 not synchronized"),
+    (46, "EL3HLT", "Level 3 halted"),
+    (47, "EL3RST", "Level 3 reset"),
+    (48, "ELNRNG", "Link number out of range"),
+    (49, "EUNATCH", "Protocol driver not attached"),
+    (50, "ENOCSI", "No CSI structure available"),
+    (51, "EL2HLT", "Level 2 halted"),
+    (52, "EBADE", "Invalid exchange"),
+    (53, "EBADR", "Invalid request descriptor"),
+    (54, "EXFULL", "Exchange full"),
+    (55, "ENOANO", "No anode"),
+    (56, "EBADRQC", "Invalid request code"),
+    (57, "EBADSLT", "Invalid slot"),
+    (59, "EBFONT", "Bad font file format"),
+    (60, "ENOSTR", "Device not a stream"),
+    (61, "ENODATA", "No data available"),
+    (62, "ETIME", "Timer expired"),
+    (63, "ENOSR", "Out of streams resources"),
+    (64, "ENONET", "Machine is not on the network"),
+    (65, "ENOPKG", "Package not installed"),
+    (66, "EREMOTE", "Object is remote"),
+    (67, "ENOLINK", "Link has been severed"),
+    (68, "EADV", "Advertise error"),
+    (69, "ESRMNT", "Srmount error"),
+    (70, "ECOMM", "Communication error on send"),
+    (71, "EPROTO", "Protocol error"),
+    (72, "EMULTIHOP", "Multihop attempted"),
+    (73, "EDOTDOT", "RFS specific error"),
+    (74, "EBADMSG", "Bad message"),
+    (75, "EOVERFLOW", "Value too large for defined data type"),
+    (76, "ENOTUNIQ", "Name not unique on network"),
+    (77, "EBADFD", "File descriptor in bad state"),
+    (78, "EREMCHG", "Remote address changed"),
+    (79, "ELIBACC", "Can not access a needed shared library"),
+    (80, "ELIBBAD", "Accessing a corrupted shared library"),
+    (81, "ELIBSCN", ".lib section in a.out corrupted"),
+    (
+        82,
+        "ELIBMAX",
+        "Attempting to link in too many shared libraries",
+    ),
+    (83, "ELIBEXEC", "Cannot exec a shared library directly"),
+    (
+        84,
+        "EILSEQ",
+        "Invalid or incomplete multibyte or wide character",
+    ),
+    (
+        85,
+        "ERESTART",
+        "Interrupted system call should be restarted",
+    ),
+    (86, "ESTRPIPE", "Streams pipe error"),
+    (87, "EUSERS", "Too many users"),
+    (88, "ENOTSOCK", "Socket operation on non-socket"),
+    (89, "EDESTADDRREQ", "Destination address required"),
+    (90, "EMSGSIZE", "Message too long"),
+    (91, "EPROTOTYPE", "Protocol wrong type for socket"),
+    (92, "ENOPROTOOPT", "Protocol not available"),
+    (93, "EPROTONOSUPPORT", "Protocol not supported"),
+    (94, "ESOCKTNOSUPPORT", "Socket type not supported"),
+    (95, "EOPNOTSUPP", "Operation not supported"),
+    (96, "EPFNOSUPPORT", "Protocol family not supported"),
+    (
+        97,
+        "EAFNOSUPPORT",
+        "Address family not supported by protocol",
+    ),
+    (98, "EADDRINUSE", "Address already in use"),
+    (99, "EADDRNOTAVAIL", "Cannot assign requested address"),
+    (100, "ENETDOWN", "Network is down"),
+    (101, "ENETUNREACH", "Network is unreachable"),
+    (102, "ENETRESET", "Network dropped connection on reset"),
+    (103, "ECONNABORTED", "Software caused connection abort"),
+    (104, "ECONNRESET", "Connection reset by peer"),
+    (105, "ENOBUFS", "No buffer space available"),
+    (106, "EISCONN", "Transport endpoint is already connected"),
+    (107, "ENOTCONN", "Transport endpoint is not connected"),
+    (
+        108,
+        "ESHUTDOWN",
+        "Cannot send after transport endpoint shutdown",
+    ),
+    (109, "ETOOMANYREFS", "Too many references: cannot splice"),
+    (110, "ETIMEDOUT", "Connection timed out"),
+    (111, "ECONNREFUSED", "Connection refused"),
+    (112, "EHOSTDOWN", "Host is down"),
+    (113, "EHOSTUNREACH", "No route to host"),
+    (114, "EALREADY", "Operation already in progress"),
+    (115, "EINPROGRESS", "Operation now in progress"),
+    (116, "ESTALE", "Stale file handle"),
+    (117, "EUCLEAN", "Structure needs cleaning"),
+    (118, "ENOTNAM", "Not a XENIX named type file"),
+    (119, "ENAVAIL", "No XENIX semaphores available"),
+    (120, "EISNAM", "Is a named type file"),
+    (121, "EREMOTEIO", "Remote I/O error"),
+    (122, "EDQUOT", "Disk quota exceeded"),
+    (123, "ENOMEDIUM", "No medium found"),
+    (124, "EMEDIUMTYPE", "Wrong medium type"),
+    (125, "ECANCELED", "Operation canceled"),
+    (126, "ENOKEY", "Required key not available"),
+    (127, "EKEYEXPIRED", "Key has expired"),
+    (128, "EKEYREVOKED", "Key has been revoked"),
+    (129, "EKEYREJECTED", "Key was rejected by service"),
+    (130, "EOWNERDEAD", "Owner died"),
+    (131, "ENOTRECOVERABLE", "State not recoverable"),
+    (132, "ERFKILL", "Operation not possible due to RF-kill"),
+    (133, "EHWPOISON", "Memory page has hardware error"),
+    // kernel internal, see `include/linux/errno.h`
+    (512, "ERESTARTSYS", "Restart system call (kernel internal)"),
+    (
+        513,
+        "ERESTARTNOINTR",
+        "Restart system call (kernel internal)",
+    ),
+    (
+        514,
+        "ERESTARTNOHAND",
+        "Restart if no handler (kernel internal)",
+    ),
+    (515, "ENOIOCTLCMD", "No ioctl command (kernel internal)"),
+    (
+        516,
+        "ERESTART_RESTARTBLOCK",
+        "Restart by calling sys_restart_syscall",
+    ),
+    (
+        517,
+        "EPROBE_DEFER",
+        "Driver requests probe retry (kernel internal)",
+    ),
+    (
+        518,
+        "EOPENSTALE",
+        "Open found a stale dentry (kernel internal)",
+    ),
+    (519, "ENOPARAM", "Parameter not supported (kernel internal)"),
+    (
+        521,
+        "EBADHANDLE",
+        "Illegal NFS file handle (kernel internal)",
+    ),
+    (
+        522,
+        "ENOTSYNC",
+        "Update synchronization mismatch (kernel internal)",
+    ),
+    (523, "EBADCOOKIE", "Cookie is stale (kernel internal)"),
+    (
+        524,
+        "ENOTSUPP",
+        "Operation is not supported (kernel internal)",
+    ),
+    (
+        525,
+        "ETOOSMALL",
+        "Buffer or request is too small (kernel internal)",
+    ),
+    (
+        526,
+        "ESERVERFAULT",
+        "An untranslatable error occurred (kernel internal)",
+    ),
+];
+
+pub(super) fn build_os_errors_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+    let signed = bits as i64;
+    // also consider 32 bit values, such as `0xfffffffe` for `-2`
+    let negated = if bits >> 32 == 0 {
+        -i64::from(bits as u32 as i32)
+    } else {
+        signed.wrapping_neg()
+    };
+
+    let lines = vec![
+        ("errno", "errno", format_errno(signed)),
+        ("neg-errno", "-errno", format_errno(negated)),
+        ("exit-status", "Exit status ($?)", format_exit_status(bits)),
+        ("wait-status", "wait() status", format_wait_status(bits)),
+        ("err-ptr", "ERR_PTR", format_err_ptr(bits)),
+    ];
+
+    OutputGroup {
+        title: Interpretation::OsErrors,
+        value_alignment: ValueAlignment::Left,
+        lines: lines
+            .into_iter()
+            .map(|(id, key, value)| OutputLine::with_id(id, key.to_string(), value))
+            .collect(),
+    }
+}
+
+/// Looks up the symbolic name and message of an error number.
+fn errno_lookup(errno: i64) -> Option<(&'static str, &'static str)> {
+    ERRNO_TABLE
+        .iter()
+        .find(|(number, _, _)| i64::from(*number) == errno)
+        .map(|(_, name, message)| (*name, *message))
+}
+
+fn format_errno(errno: i64) -> String {
+    errno_lookup(errno).map_or_else(
+        || "-".to_string(),
+        |(name, message)| format!("{name} ({message})"),
+    )
+}
+
+/// Interprets the value as exit status of a shell command, where values
+/// above 128 indicate that the process was killed by a signal.
+fn format_exit_status(status: u64) -> String {
+    match status {
+        0 => "success".to_string(),
+        126 => "command not executable".to_string(),
+        127 => "command not found".to_string(),
+        129..=192 => signal_name(status - 128).map_or_else(
+            || format!("exit code {status}"),
+            |name| format!("killed by {name}"),
+        ),
+        1..=255 => format!("exit code {status}"),
+        _ => "-".to_string(),
+    }
+}
+
+/// Decodes a status as returned by `wait()`/`waitpid()` on Linux, see
+/// `WIFEXITED`, `WIFSIGNALED`, `WIFSTOPPED`, and `WIFCONTINUED`.
+fn format_wait_status(status: u64) -> String {
+    if status > 0xffff {
+        return "-".to_string();
+    }
+    let signal = status & 0x7f;
+    let signal_string =
+        |signal: u64| signal_name(signal).unwrap_or_else(|| format!("signal {signal}"));
+    if status == 0xffff {
+        "continued".to_string()
+    } else if signal == 0 {
+        format!("exited, code {}", status >> 8)
+    } else if status & 0xff == 0x7f {
+        format!("stopped by {}", signal_string(status >> 8))
+    } else {
+        format!(
+            "killed by {}{}",
+            signal_string(signal),
+            if status & 0x80 == 0 {
+                ""
+            } else {
+                ", core dumped"
+            }
+        )
+    }
+}
+
+/// Recognizes error numbers encoded in kernel pointers via `ERR_PTR()`.
+/// These are the highest [`MAX_ERRNO`] addresses, for 64 and 32 bit.
+fn format_err_ptr(bits: u64) -> String {
+    let (errno, suffix) = if bits > u64::MAX - MAX_ERRNO {
+        (u64::MAX - bits + 1, "")
+    } else if bits <= u64::from(u32::MAX) && bits > u64::from(u32::MAX) - MAX_ERRNO {
+        (u64::from(u32::MAX) - bits + 1, " (32 bit)")
+    } else {
+        return "-".to_string();
+    };
+    let name =
+        errno_lookup(errno as i64).map_or_else(|| errno.to_string(), |(name, _)| name.to_string());
+    format!("ERR_PTR(-{name}){suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+
+    fn os_error_values(input: &str) -> Vec<String> {
+        let parsed = parse_input(input, &Default::default()).unwrap();
+        build_os_errors_og(&parsed)
+            .lines
+            .into_iter()
+            .map(|line| line.value.format(&Default::default()))
+            .collect()
+    }
+
+    #[test]
+    fn test_os_errors_og() {
+        assert_eq!(
+            vec![
+                "-",
+                "ENOENT (No such file or directory)",
+                "-",
+                "-",
+                "ERR_PTR(-ENOENT)",
+            ],
+            os_error_values("-2")
+        );
+        assert_eq!(
+            vec![
+                "-",
+                "ENOMEM (Cannot allocate memory)",
+                "-",
+                "-",
+                "ERR_PTR(-ENOMEM) (32 bit)",
+            ],
+            os_error_values("0xfffffff4")
+        );
+        assert_eq!(
+            vec![
+                "EAGAIN (Resource temporarily unavailable)",
+                "-",
+                "exit code 11",
+                "killed by SIGSEGV",
+                "-",
+            ],
+            os_error_values("11")
+        );
+        assert_eq!("killed by SIGKILL", os_error_values("137")[2]);
+        assert_eq!("killed by SIGABRT, core dumped", os_error_values("0x86")[3]);
+        assert_eq!("exited, code 1", os_error_values("0x100")[3]);
+        assert_eq!("stopped by SIGTSTP", os_error_values("0x147f")[3]);
+        assert_eq!("ERR_PTR(-EPROBE_DEFER)", os_error_values("-517")[4]);
+    }
+}
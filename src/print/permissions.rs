@@ -0,0 +1,144 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for Unix file modes and flag sets.
+
+use super::{Interpretation, OutputGroup, OutputLine, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use crate::parse::flags::{MMAP_FLAGS, MMAP_PROT, OPEN_FLAGS, signal_name};
+
+/// Maximum number of signal names that are listed for a signal mask.
+const MAX_LISTED_SIGNALS: usize = 6;
+
+pub(super) fn build_permissions_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+
+    let lines = vec![
+        ("mode", format_mode(bits)),
+        ("file type", format_file_type(bits)),
+        ("open flags", OPEN_FLAGS.decode(bits)),
+        ("mmap prot", MMAP_PROT.decode(bits)),
+        ("mmap flags", MMAP_FLAGS.decode(bits)),
+        ("signal mask", format_signal_mask(bits)),
+    ];
+
+    OutputGroup {
+        title: Interpretation::Permissions,
+        value_alignment: ValueAlignment::Left,
+        lines: lines
+            .into_iter()
+            .map(|(key, value)| OutputLine::new(key.to_string(), value))
+            .collect(),
+    }
+}
+
+/// Formats the mode bits like `ls -l`, such as `drwxr-xr-x` or `-rwsr-xr-x`.
+fn format_mode(mode: u64) -> String {
+    if mode > 0o177777 {
+        return "-".to_string();
+    }
+    let type_char = match mode & 0o170000 {
+        0o140000 => 's',
+        0o120000 => 'l',
+        0o060000 => 'b',
+        0o040000 => 'd',
+        0o020000 => 'c',
+        0o010000 => 'p',
+        _ => '-',
+    };
+    // (read, write, execute bit, special bit, special char if executable, special char if not)
+    let triplets = [
+        (0o400, 0o200, 0o100, 0o4000, 's', 'S'),
+        (0o040, 0o020, 0o010, 0o2000, 's', 'S'),
+        (0o004, 0o002, 0o001, 0o1000, 't', 'T'),
+    ];
+
+    let mut string = String::from(type_char);
+    for (read, write, execute, special, special_x, special_no_x) in triplets {
+        string.push(if mode & read == 0 { '-' } else { 'r' });
+        string.push(if mode & write == 0 { '-' } else { 'w' });
+        string.push(match (mode & execute != 0, mode & special != 0) {
+            (true, true) => special_x,
+            (false, true) => special_no_x,
+            (true, false) => 'x',
+            (false, false) => '-',
+        });
+    }
+    string
+}
+
+fn format_file_type(mode: u64) -> String {
+    let file_type = match mode & 0o170000 {
+        0o140000 => "S_IFSOCK (socket)",
+        0o120000 => "S_IFLNK (symbolic link)",
+        0o100000 => "S_IFREG (regular file)",
+        0o060000 => "S_IFBLK (block device)",
+        0o040000 => "S_IFDIR (directory)",
+        0o020000 => "S_IFCHR (character device)",
+        0o010000 => "S_IFIFO (FIFO)",
+        _ => "-",
+    };
+    if mode > 0o177777 { "-" } else { file_type }.to_string()
+}
+
+/// Formats a signal mask, where bit `n - 1` stands for signal `n`.
+fn format_signal_mask(mask: u64) -> String {
+    let names = (0..64)
+        .filter(|bit| mask & (1 << bit) != 0)
+        .map(|bit| signal_name(bit + 1).unwrap_or_else(|| format!("SIG{}", bit + 1)))
+        .collect::<Vec<_>>();
+    match names.len() {
+        0 => "(empty)".to_string(),
+        n if n > MAX_LISTED_SIGNALS => format!(
+            "{}|... ({n} signals)",
+            names[..MAX_LISTED_SIGNALS].join("|")
+        ),
+        _ => names.join("|"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_mode() {
+        assert_eq!("-rwxr-xr-x", format_mode(0o755));
+        assert_eq!("drwxr-xr-x", format_mode(0o40755));
+        assert_eq!("-rwsr-Sr-t", format_mode(0o7745));
+        assert_eq!("-", format_mode(0o1000000));
+        assert_eq!("S_IFDIR (directory)", format_file_type(0o40755));
+    }
+
+    #[test]
+    fn test_format_signal_mask() {
+        assert_eq!("(empty)", format_signal_mask(0));
+        assert_eq!("SIGINT|SIGTERM", format_signal_mask(0x4002));
+        assert_eq!("SIGRTMIN+0", format_signal_mask(1 << 33));
+        assert_eq!(
+            "SIGHUP|SIGINT|SIGQUIT|SIGILL|SIGTRAP|SIGABRT|... (64 signals)",
+            format_signal_mask(u64::MAX)
+        );
+    }
+}
@@ -0,0 +1,304 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for timestamp interpretations. Everything is computed offline
+//! in UTC; there is no time zone support.
+
+use super::{Date, Interpretation, OutputGroup, OutputLine, Value, ValueAlignment};
+use crate::parse::ParsedUserInput;
+
+/// Seconds between 1601-01-01 (Windows FILETIME epoch) and the Unix epoch.
+const FILETIME_EPOCH_OFFSET: i128 = 11_644_473_600;
+/// Seconds between 1900-01-01 (NTP epoch) and the Unix epoch.
+const NTP_EPOCH_OFFSET: i128 = 2_208_988_800;
+/// Seconds between 1980-01-06 (GPS epoch) and the Unix epoch.
+const GPS_EPOCH_OFFSET: i128 = 315_964_800;
+
+/// Unix timestamps (in UTC) of all leap seconds since the GPS epoch. GPS
+/// time doesn't have leap seconds, so they must be subtracted to get UTC.
+/// In GPS time, the `i`-th leap second (counting from zero) takes effect at
+/// `LEAP_SECONDS[i] + i + 1`, as the previous leap seconds are already
+/// included.
+const LEAP_SECONDS: [i128; 18] = [
+    362_793_600,   // 1981-07-01
+    394_329_600,   // 1982-07-01
+    425_865_600,   // 1983-07-01
+    489_024_000,   // 1985-07-01
+    567_993_600,   // 1988-01-01
+    631_152_000,   // 1990-01-01
+    662_688_000,   // 1991-01-01
+    709_948_800,   // 1992-07-01
+    741_484_800,   // 1993-07-01
+    773_020_800,   // 1994-07-01
+    820_454_400,   // 1996-01-01
+    867_715_200,   // 1997-07-01
+    915_148_800,   // 1999-01-01
+    1_136_073_600, // 2006-01-01
+    1_230_768_000, // 2009-01-01
+    1_341_100_800, // 2012-07-01
+    1_435_708_800, // 2015-07-01
+    1_483_228_800, // 2017-01-01
+];
+
+/// Unix timestamps that are considered plausible: 1990-01-01 to 2100-01-01.
+const PLAUSIBLE_RANGE: std::ops::Range<i128> = 631_152_000..4_102_444_800;
+
+pub(super) fn build_timestamps_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+    let signed = i128::from(bits as i64);
+    let unsigned = i128::from(bits);
+
+    let ntp_fraction_nanos = ((bits & 0xffff_ffff) * 1_000_000_000) >> 32;
+    let gps_seconds = unsigned + GPS_EPOCH_OFFSET;
+    let gps_seconds = gps_seconds
+        - LEAP_SECONDS
+            .iter()
+            .enumerate()
+            .filter(|(i, leap_second)| **leap_second + (*i as i128) < gps_seconds)
+            .count() as i128;
+
+    let mut lines = vec![
+        ("Unix (s)", date(signed, 0, 0)),
+        (
+            "Unix (ms)",
+            date(
+                signed.div_euclid(1_000),
+                signed.rem_euclid(1_000) * 1_000_000,
+                3,
+            ),
+        ),
+        (
+            "Unix (us)",
+            date(
+                signed.div_euclid(1_000_000),
+                signed.rem_euclid(1_000_000) * 1_000,
+                6,
+            ),
+        ),
+        (
+            "Unix (ns)",
+            date(
+                signed.div_euclid(1_000_000_000),
+                signed.rem_euclid(1_000_000_000),
+                9,
+            ),
+        ),
+        (
+            "FILETIME",
+            date(
+                unsigned / 10_000_000 - FILETIME_EPOCH_OFFSET,
+                unsigned % 10_000_000 * 100,
+                7,
+            ),
+        ),
+        ("NTP (s)", date(unsigned - NTP_EPOCH_OFFSET, 0, 0)),
+        (
+            "NTP (32.32)",
+            date(
+                i128::from(bits >> 32) - NTP_EPOCH_OFFSET,
+                i128::from(ntp_fraction_nanos),
+                9,
+            ),
+        ),
+        ("GPS (s)", date(gps_seconds, 0, 0)),
+        ("DOS/FAT", format_dos_date_time(bits as u32).into()),
+    ];
+    let plausible = lines
+        .iter()
+        .filter(|(_, value)| {
+            matches!(value, Value::Date(date) if PLAUSIBLE_RANGE.contains(&date.seconds))
+        })
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+    let plausible = if plausible.is_empty() {
+        "(none)".to_string()
+    } else {
+        plausible.join(", ")
+    };
+    lines.push(("Plausible", plausible.into()));
+
+    OutputGroup {
+        title: Interpretation::Timestamps,
+        value_alignment: ValueAlignment::Left,
+        lines: lines
+            .into_iter()
+            .map(|(key, value)| OutputLine::new(key.to_string(), value))
+            .collect(),
+    }
+}
+
+/// A Unix timestamp with `frac_digits` digits of the `nanos` (`0..=9`).
+const fn date(seconds: i128, nanos: i128, frac_digits: usize) -> Value {
+    Value::Date(Date {
+        seconds,
+        nanos,
+        frac_digits,
+    })
+}
+
+/// Formats a Unix timestamp as UTC date and time with `frac_digits` digits
+/// of the `nanos` (`0..=9`).
+pub(super) fn format_timestamp(seconds: i128, nanos: i128, frac_digits: usize) -> String {
+    let Some((year, month, day)) = civil_from_days(seconds.div_euclid(86_400)) else {
+        return "(out of range)".to_string();
+    };
+    let seconds_of_day = seconds.rem_euclid(86_400);
+    let mut string = format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    );
+    if frac_digits > 0 {
+        let nanos = format!("{nanos:09}");
+        string.push('.');
+        string.push_str(&nanos[..frac_digits]);
+    }
+    string.push_str(" UTC");
+    string
+}
+
+/// Formats a packed MS-DOS/FAT date and time. The date is in the upper
+/// 16 bits and the time in the lower 16 bits. The DOS epoch is 1980 and
+/// seconds are stored with a resolution of two seconds.
+fn format_dos_date_time(packed: u32) -> String {
+    let year = 1980 + (packed >> 25);
+    let month = (packed >> 21) & 0xf;
+    let day = (packed >> 16) & 0x1f;
+    let hours = (packed >> 11) & 0x1f;
+    let minutes = (packed >> 5) & 0x3f;
+    let seconds = (packed & 0x1f) * 2;
+
+    let is_valid = (1..=12).contains(&month)
+        && (1..=days_in_month(i128::from(year), month)).contains(&day)
+        && hours < 24
+        && minutes < 60
+        && seconds < 60;
+    if !is_valid {
+        return "(invalid)".to_string();
+    }
+    format!("{year:04}-{month:02}-{day:02} {hours:02}:{minutes:02}:{seconds:02} (local)")
+}
+
+const fn is_leap_year(year: i128) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: i128, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts days since the Unix epoch to a date in the proleptic Gregorian
+/// calendar. Returns `None` for years outside of `0..=9999`. Based on
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i128) -> Option<(i128, u32, u32)> {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i128::from(month <= 2);
+    (0..=9999).contains(&year).then_some((year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(Some((1970, 1, 1)), civil_from_days(0));
+        assert_eq!(Some((1969, 12, 31)), civil_from_days(-1));
+        assert_eq!(Some((2000, 2, 29)), civil_from_days(11_016));
+        assert_eq!(None, civil_from_days(i128::from(i64::MAX)));
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(
+            "2009-02-13 23:31:30 UTC",
+            format_timestamp(1_234_567_890, 0, 0)
+        );
+        assert_eq!(
+            "1969-12-31 23:59:59.999 UTC",
+            format_timestamp(-1, 999_000_000, 3)
+        );
+    }
+
+    #[test]
+    fn test_format_dos_date_time() {
+        // 2021-03-14 15:09:26
+        assert_eq!(
+            "2021-03-14 15:09:26 (local)",
+            format_dos_date_time(0x526e_792d)
+        );
+        assert_eq!("(invalid)", format_dos_date_time(0));
+    }
+
+    #[test]
+    fn test_timestamps_og() {
+        let parsed = crate::parse::parse_input("1234567890", &Default::default()).unwrap();
+        let og = build_timestamps_og(&parsed);
+        assert_eq!(
+            "2009-02-13 23:31:30 UTC",
+            og.lines[0].value.format(&Default::default())
+        );
+
+        // GPS week 2000, second 0: 2018-05-06 minus 18 leap seconds
+        let parsed = crate::parse::parse_input("1209600000", &Default::default()).unwrap();
+        let og = build_timestamps_og(&parsed);
+        assert_eq!(
+            "2018-05-05 23:59:42 UTC",
+            og.lines[7].value.format(&Default::default())
+        );
+
+        // 2024-01-01 00:00:00 as FILETIME
+        let parsed = crate::parse::parse_input("133485408000000000", &Default::default()).unwrap();
+        let og = build_timestamps_og(&parsed);
+        assert_eq!(
+            "2024-01-01 00:00:00.0000000 UTC",
+            og.lines[4].value.format(&Default::default())
+        );
+        assert_eq!("Plausible", og.lines[9].key);
+        assert_eq!("FILETIME", og.lines[9].value.format(&Default::default()));
+
+        // GPS 2017-01-01 00:00:10 is 10 s after the last leap second in UTC,
+        // but still before it took effect in GPS time (at 00:00:18)
+        let parsed = crate::parse::parse_input("1167264010", &Default::default()).unwrap();
+        let og = build_timestamps_og(&parsed);
+        assert_eq!(
+            "2016-12-31 23:59:53 UTC",
+            og.lines[7].value.format(&Default::default())
+        );
+    }
+}
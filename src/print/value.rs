@@ -0,0 +1,780 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Typed values of output lines. They are formatted at render time, so that
+//! the precision and the digit grouping can change without rebuilding the
+//! output groups, and machine-readable output gets the raw values.
+
+use super::timestamps::format_timestamp;
+use super::{DEFAULT_PRECISION, MAX_PRECISION};
+use crate::parse::NumeralSystem;
+use derive_more::Display;
+use fraction_list_fmt_align::{
+    FormatPrecision, FractionNumber, fmt_align_fraction_strings, fmt_align_fractions,
+};
+
+/// Options that influence how values are formatted at render time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// How floats and sizes are formatted.
+    pub precision: Precision,
+    /// Separate groups of digits in the numeral systems with `_`.
+    pub digit_grouping: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            precision: Precision::Max(DEFAULT_PRECISION),
+            digit_grouping: false,
+        }
+    }
+}
+
+/// How floats and sizes are formatted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display)]
+pub enum Precision {
+    /// At most this many fraction digits, without trailing zeros.
+    #[display("{_0}")]
+    Max(u8),
+    /// Exactly this many fraction digits.
+    #[display("fixed:{_0}")]
+    Fixed(u8),
+    /// The shortest representation that reads back as the same float.
+    #[display("shortest")]
+    Shortest,
+    /// All digits of the binary value, without rounding.
+    #[display("exact")]
+    Exact,
+    /// Hex-float notation, as `%a` in C, such as `0x1.4p+2`.
+    #[display("hex")]
+    Hex,
+}
+
+impl Precision {
+    /// Parses the [`Precision`] from a normalized string, such as `4`,
+    /// `fixed:8`, or `exact`.
+    pub fn from_input(input: &str) -> Option<Self> {
+        let digits = |digits: &str| {
+            digits
+                .parse::<u8>()
+                .ok()
+                .filter(|digits| *digits <= MAX_PRECISION)
+        };
+        match input {
+            "shortest" => Some(Self::Shortest),
+            "exact" => Some(Self::Exact),
+            "hex" => Some(Self::Hex),
+            _ => input.strip_prefix("fixed:").map_or_else(
+                || digits(input).map(Self::Max),
+                |fixed| digits(fixed).map(Self::Fixed),
+            ),
+        }
+    }
+
+    /// The next precision, used to cycle from the `configured` precision
+    /// through the other modes.
+    pub const fn next(self, configured: Self) -> Self {
+        match self {
+            Self::Max(_) | Self::Fixed(_) => Self::Shortest,
+            Self::Shortest => Self::Exact,
+            Self::Exact => Self::Hex,
+            Self::Hex => match configured {
+                Self::Max(_) | Self::Fixed(_) => configured,
+                _ => Self::Shortest,
+            },
+        }
+    }
+}
+
+/// The value of an [`super::OutputLine`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An integer, shown in a numeral system.
+    Integer(Integer),
+    /// A floating-point number, shown with the precision of the
+    /// [`FormatOptions`].
+    Float(Float),
+    /// A number of bytes in a unit, shown with the precision of the
+    /// [`FormatOptions`].
+    Size(Size),
+    /// An exact decimal number with any number of fraction digits, such as
+    /// `-1.25`. Shown as is.
+    Decimal(String),
+    /// A byte sequence, shown as hex dump with the number of bytes.
+    Bytes(Vec<u8>),
+    /// A point in time, shown as UTC date and time.
+    Date(Date),
+    /// Anything else, such as names and composed values.
+    Text(String),
+}
+
+impl Value {
+    /// Formats the value on its own, i.e., not aligned with other values.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        match self {
+            Self::Integer(integer) => integer.format(options),
+            Self::Float(float) => float.format(options.precision),
+            Self::Size(size) => size.format(options.precision),
+            Self::Decimal(decimal) => decimal.clone(),
+            Self::Bytes(bytes) => format_bytes(bytes),
+            Self::Date(date) => format_timestamp(date.seconds, date.nanos, date.frac_digits),
+            Self::Text(text) => text.clone(),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Size> for Value {
+    fn from(size: Size) -> Self {
+        Self::Size(size)
+    }
+}
+
+impl From<Integer> for Value {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl From<Float> for Value {
+    fn from(float: Float) -> Self {
+        Self::Float(float)
+    }
+}
+
+/// An integer with its width and signedness.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Integer {
+    pub value: i128,
+    /// Width in bits.
+    pub width: u32,
+    pub signed: bool,
+    pub radix: NumeralSystem,
+    /// Show all `width` bits with leading zeros and the prefix of the
+    /// numeral system, such as `0x00ff`. Only for non-negative values.
+    pub padded: bool,
+    /// Separate groups of digits with `_`. If `None`, the
+    /// [`FormatOptions`] decide.
+    pub grouped: Option<bool>,
+}
+
+impl Integer {
+    /// A decimal integer that is never grouped, such as the value of an
+    /// `i16`.
+    pub const fn decimal(value: i128, width: u32, signed: bool) -> Self {
+        Self {
+            value,
+            width,
+            signed,
+            radix: NumeralSystem::Decimal,
+            padded: false,
+            grouped: Some(false),
+        }
+    }
+
+    fn format(&self, options: &FormatOptions) -> String {
+        self.format_digits(options, self.padded)
+    }
+
+    /// Formats the integer with the prefix of its numeral system, as in
+    /// source code, such as `0x40a00000`.
+    pub fn format_prefixed(&self, options: &FormatOptions) -> String {
+        self.format_digits(options, true)
+    }
+
+    fn format_digits(&self, options: &FormatOptions, prefixed: bool) -> String {
+        let magnitude = self.value.unsigned_abs();
+        let (digits, chunksize, prefix, bits_per_digit) = match self.radix {
+            NumeralSystem::Bin => (format!("{magnitude:b}"), 8, "0b", 1),
+            NumeralSystem::Octal => (format!("{magnitude:o}"), 3, "0o", 3),
+            NumeralSystem::Decimal => (format!("{magnitude}"), 3, "", 0),
+            NumeralSystem::Hex => (format!("{magnitude:x}"), 4, "0x", 4),
+        };
+        let digits = if self.padded && bits_per_digit > 0 {
+            let len = self.width.div_ceil(bits_per_digit) as usize;
+            format!("{digits:0>len$}")
+        } else {
+            digits
+        };
+        let digits = if self.grouped.unwrap_or(options.digit_grouping) {
+            group_digits(&digits, chunksize)
+        } else {
+            digits
+        };
+        let sign = if self.value < 0 { "-" } else { "" };
+        let prefix = if prefixed { prefix } else { "" };
+        format!("{sign}{prefix}{digits}")
+    }
+}
+
+/// A floating-point number, with the type that it was read as.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Float {
+    /// The value. Values of `f32`s are converted losslessly.
+    pub value: f64,
+    pub format: FloatFormat,
+}
+
+impl Float {
+    pub fn f32(value: f32) -> Self {
+        Self {
+            value: f64::from(value),
+            format: FloatFormat::F32,
+        }
+    }
+
+    pub const fn f64(value: f64) -> Self {
+        Self {
+            value,
+            format: FloatFormat::F64,
+        }
+    }
+
+    const fn fraction_number(self) -> FractionNumber {
+        match self.format {
+            FloatFormat::F32 => FractionNumber::F32(self.value as f32),
+            FloatFormat::F64 => FractionNumber::F64(self.value),
+        }
+    }
+
+    fn format(self, precision: Precision) -> String {
+        match precision {
+            Precision::Max(digits) => {
+                fmt_align_fractions(&[self.fraction_number()], FormatPrecision::Max(digits))[0]
+                    .trim()
+                    .to_string()
+            }
+            // the f64 of an f32 has the same digits, as it has the same value
+            Precision::Fixed(digits) => format!("{:.*}", usize::from(digits), self.value),
+            Precision::Shortest => match self.format {
+                FloatFormat::F32 => (self.value as f32).to_string(),
+                FloatFormat::F64 => self.value.to_string(),
+            },
+            Precision::Exact => exact_decimal(self.value),
+            Precision::Hex => hex_float(self.value),
+        }
+    }
+}
+
+/// A size as number of bytes in a unit, such as KiB.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Size {
+    pub bytes: u64,
+    /// Bytes per unit, a power of 1000 or 1024.
+    pub unit: u64,
+}
+
+impl Size {
+    /// The size in the unit, possibly rounded.
+    pub fn value(self) -> f64 {
+        self.bytes as f64 / self.unit as f64
+    }
+
+    /// Whether the unit is bytes, so that the size is an integer.
+    pub const fn is_in_bytes(self) -> bool {
+        self.unit == 1
+    }
+
+    fn format(self, precision: Precision) -> String {
+        match precision {
+            // an integer is exact in every precision
+            _ if self.is_in_bytes() => self.bytes.to_string(),
+            Precision::Exact => exact_quotient(self.bytes, self.unit),
+            precision => Float::f64(self.value()).format(precision),
+        }
+    }
+}
+
+/// IEEE-754 binary formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloatFormat {
+    F32,
+    F64,
+}
+
+/// A point in time as seconds since the Unix epoch in UTC.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Date {
+    pub seconds: i128,
+    /// Nanoseconds, `0..1_000_000_000`.
+    pub nanos: i128,
+    /// Shown digits of the fraction of a second, `0..=9`.
+    pub frac_digits: usize,
+}
+
+/// Formats the values of a group, such that floats, sizes, and decimals
+/// are aligned at the decimal point. Other values are formatted on their
+/// own.
+pub fn format_values(values: &[&Value], options: &FormatOptions) -> Vec<String> {
+    let numbers = values
+        .iter()
+        .filter(|value| matches!(value, Value::Float(_) | Value::Size(_)))
+        .copied()
+        .collect::<Vec<_>>();
+    let decimals = values
+        .iter()
+        .filter_map(|value| match value {
+            Value::Decimal(decimal) => Some(decimal.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut numbers = format_numbers(&numbers, options.precision).into_iter();
+    let mut decimals = if decimals.is_empty() {
+        Vec::new()
+    } else {
+        fmt_align_fraction_strings(&decimals)
+    }
+    .into_iter();
+    values
+        .iter()
+        .map(|value| match value {
+            Value::Float(_) | Value::Size(_) => numbers.next().unwrap(),
+            Value::Decimal(_) => decimals.next().unwrap(),
+            value => value.format(options),
+        })
+        .collect()
+}
+
+/// Formats floats and sizes with the precision, aligned at the decimal
+/// point. Hex-floats are not aligned. Sizes in bytes are integers and stay
+/// exact.
+fn format_numbers(numbers: &[&Value], precision: Precision) -> Vec<String> {
+    match precision {
+        _ if numbers.is_empty() => Vec::new(),
+        // keeps the alignment of the trimmed fraction digits
+        Precision::Max(digits) => {
+            let fractions = numbers
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Float(float) => Some(float.fraction_number()),
+                    Value::Size(size) if size.is_in_bytes() => None,
+                    Value::Size(size) => Some(FractionNumber::F64(size.value())),
+                    _ => unreachable!("only floats and sizes are numbers"),
+                })
+                .collect::<Vec<_>>();
+            let mut fractions = if fractions.is_empty() {
+                Vec::new()
+            } else {
+                fmt_align_fractions(&fractions, FormatPrecision::Max(digits))
+            }
+            .into_iter();
+            let numbers = numbers
+                .iter()
+                .map(|value| match value {
+                    Value::Size(size) if size.is_in_bytes() => size.bytes.to_string(),
+                    _ => fractions.next().unwrap().trim().to_string(),
+                })
+                .collect::<Vec<_>>();
+            align_at_decimal_point(&numbers)
+        }
+        precision => {
+            let options = FormatOptions {
+                precision,
+                ..FormatOptions::default()
+            };
+            let numbers = numbers
+                .iter()
+                .map(|value| value.format(&options))
+                .collect::<Vec<_>>();
+            if precision == Precision::Hex {
+                numbers
+            } else {
+                align_at_decimal_point(&numbers)
+            }
+        }
+    }
+}
+
+/// Pads the numbers, such that their decimal points are aligned and all
+/// have the same width. Unlike [`fmt_align_fraction_strings`], trailing
+/// zeros are kept.
+fn align_at_decimal_point(numbers: &[String]) -> Vec<String> {
+    let whole_part_len = |number: &String| number.split('.').next().unwrap().chars().count();
+    let max_whole_part_len = numbers.iter().map(whole_part_len).max().unwrap_or(0);
+    let numbers = numbers
+        .iter()
+        .map(|number| {
+            let padding = max_whole_part_len - whole_part_len(number);
+            format!("{}{number}", " ".repeat(padding))
+        })
+        .collect::<Vec<_>>();
+    let max_len = numbers.iter().map(|number| number.chars().count()).max();
+    numbers
+        .into_iter()
+        .map(|number| format!("{number:<width$}", width = max_len.unwrap_or(0)))
+        .collect()
+}
+
+/// Number of decimal digits in a limb of [`exact_decimal`].
+const LIMB_DIGITS: usize = 9;
+const LIMB: u64 = 10_u64.pow(LIMB_DIGITS as u32);
+
+/// The exact decimal expansion of the float. There always is one, as the
+/// value is `mantissa * 2^exponent`, and `2^-n` is `5^n / 10^n`.
+fn exact_decimal(value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let bits = value.to_bits();
+    let sign = if bits >> 63 == 1 { "-" } else { "" };
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, biased_exponent - 1075)
+    };
+
+    // decimal digits of the mantissa, as little-endian limbs
+    let mut limbs = vec![
+        mantissa % LIMB,
+        mantissa / LIMB % LIMB,
+        mantissa / LIMB / LIMB,
+    ];
+    let fraction_digits = if exponent >= 0 {
+        // 2^29 times a limb fits into u64
+        multiply_limbs(&mut limbs, 2, exponent.unsigned_abs(), 29);
+        0
+    } else {
+        // 5^12 times a limb fits into u64
+        multiply_limbs(&mut limbs, 5, exponent.unsigned_abs(), 12);
+        exponent.unsigned_abs() as usize
+    };
+    while limbs.len() > 1 && limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    let mut digits = limbs.last().unwrap().to_string();
+    for limb in limbs.iter().rev().skip(1) {
+        digits += &format!("{limb:0LIMB_DIGITS$}");
+    }
+
+    let digits = format!("{digits:0>width$}", width = fraction_digits + 1);
+    let (whole_part, fraction_part) = digits.split_at(digits.len() - fraction_digits);
+    let fraction_part = fraction_part.trim_end_matches('0');
+    if fraction_part.is_empty() {
+        format!("{sign}{whole_part}")
+    } else {
+        format!("{sign}{whole_part}.{fraction_part}")
+    }
+}
+
+/// Multiplies the number in `limbs` by `base^exponent`, in steps of at most
+/// `base^max_step`.
+fn multiply_limbs(limbs: &mut Vec<u64>, base: u64, mut exponent: u32, max_step: u32) {
+    while exponent > 0 {
+        let step = exponent.min(max_step);
+        let factor = base.pow(step);
+        let mut carry = 0;
+        for limb in limbs.iter_mut() {
+            let product = *limb * factor + carry;
+            *limb = product % LIMB;
+            carry = product / LIMB;
+        }
+        while carry > 0 {
+            limbs.push(carry % LIMB);
+            carry /= LIMB;
+        }
+        exponent -= step;
+    }
+}
+
+/// The exact decimal expansion of `dividend / divisor`. The divisor must
+/// only have the prime factors 2 and 5, such as the bytes of a unit, so
+/// that the expansion ends.
+fn exact_quotient(dividend: u64, divisor: u64) -> String {
+    let mut quotient = (dividend / divisor).to_string();
+    let mut remainder = dividend % divisor;
+    if remainder > 0 {
+        quotient.push('.');
+    }
+    while remainder > 0 {
+        remainder *= 10;
+        quotient += &(remainder / divisor).to_string();
+        remainder %= divisor;
+    }
+    quotient
+}
+
+/// Formats the float in hex-float notation, as `%a` in C: `5.0` becomes
+/// `0x1.4p+2`.
+fn hex_float(value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let bits = value.to_bits();
+    let sign = if bits >> 63 == 1 { "-" } else { "" };
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (leading_digit, exponent) = match (biased_exponent, fraction) {
+        (0, 0) => return format!("{sign}0x0p+0"),
+        // subnormal
+        (0, _) => (0, -1022),
+        _ => (1, biased_exponent - 1023),
+    };
+    let digits = format!("{fraction:013x}");
+    let digits = digits.trim_end_matches('0');
+    let point = if digits.is_empty() { "" } else { "." };
+    format!("{sign}0x{leading_digit}{point}{digits}p{exponent:+}")
+}
+
+/// Formats the bytes as hex dump, followed by their count.
+pub(super) fn format_bytes(bytes: &[u8]) -> String {
+    let hex_dump = bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "{hex_dump} ({} byte{})",
+        bytes.len(),
+        if bytes.len() == 1 { "" } else { "s" }
+    )
+}
+
+/// Transforms for example "1111000010101010" to "11110000_10101010" to
+/// increase readability.
+fn format_num_add_delimiters(digits: &str, chunksize: usize) -> String {
+    let chars = digits.chars().collect::<Vec<char>>();
+    assert_eq!(chars.len() % chunksize, 0);
+    let formatted_with_delimiters = chars
+        .chunks(chunksize)
+        .map(|chars| chars.iter().collect::<String>())
+        .fold(String::new(), |combined: String, group| {
+            format!("{}_{}", combined, group)
+        });
+
+    // transform _00000000_00000000 to 00000000_00000000 (remove leading underscore)
+    formatted_with_delimiters
+        .chars()
+        .skip(1) // skip first item
+        .collect::<String>()
+}
+
+/// Separates groups of `chunksize` digits with `_`, starting from the
+/// right: `1234567` becomes `1_234_567`.
+fn group_digits(digits: &str, chunksize: usize) -> String {
+    // pad the first group, so that all groups have the same size
+    let padding = (chunksize - digits.len() % chunksize) % chunksize;
+    let padded = format!("{}{}", " ".repeat(padding), digits);
+    format_num_add_delimiters(&padded, chunksize)
+        .trim_start()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!("1_234_567", group_digits("1234567", 3));
+        assert_eq!("123", group_digits("123", 3));
+        assert_eq!("1_0000", group_digits("10000", 4));
+        assert_eq!("", group_digits("", 4));
+    }
+
+    #[test]
+    fn test_format_integer() {
+        let options = FormatOptions::default();
+        let grouping = FormatOptions {
+            digit_grouping: true,
+            ..options
+        };
+        let integer = Integer {
+            value: -0xdead_beef,
+            width: 64,
+            signed: true,
+            radix: NumeralSystem::Hex,
+            padded: false,
+            grouped: None,
+        };
+        assert_eq!("-deadbeef", integer.format(&options));
+        assert_eq!("-dead_beef", integer.format(&grouping));
+        let integer = Integer {
+            value: 0xff,
+            width: 16,
+            signed: false,
+            radix: NumeralSystem::Bin,
+            padded: true,
+            grouped: Some(true),
+        };
+        assert_eq!("0b00000000_11111111", integer.format(&options));
+        assert_eq!(
+            "1234567",
+            Integer::decimal(1_234_567, 32, true).format(&grouping)
+        );
+    }
+
+    #[test]
+    fn test_format_values() {
+        let values = [
+            Value::Float(Float::f64(1000.0)),
+            Value::Text("foo".to_string()),
+            Value::Float(Float::f64(0.25)),
+            Value::Bytes(vec![0xe5, 0x8e, 0x26]),
+        ];
+        assert_eq!(
+            vec!["1000   ", "foo", "   0.25", "e5 8e 26 (3 bytes)"],
+            format_values(
+                &values.iter().collect::<Vec<_>>(),
+                &FormatOptions::default()
+            )
+        );
+        assert_eq!("0.25", values[2].format(&FormatOptions::default()));
+
+        let fixed = FormatOptions {
+            precision: Precision::Fixed(2),
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            vec!["1000.00", "foo", "   0.25", "e5 8e 26 (3 bytes)"],
+            format_values(&values.iter().collect::<Vec<_>>(), &fixed)
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(Some(Precision::Max(4)), Precision::from_input("4"));
+        assert_eq!(Some(Precision::Fixed(0)), Precision::from_input("fixed:0"));
+        assert_eq!(Some(Precision::Hex), Precision::from_input("hex"));
+        assert_eq!(None, Precision::from_input("21"));
+        assert_eq!(None, Precision::from_input("fixed:"));
+        for precision in [Precision::Fixed(8), Precision::Shortest, Precision::Exact] {
+            assert_eq!(
+                Some(precision),
+                Precision::from_input(&precision.to_string())
+            );
+        }
+
+        let configured = Precision::Max(4);
+        let mut precision = configured;
+        let mut cycle = Vec::new();
+        for _ in 0..4 {
+            precision = precision.next(configured);
+            cycle.push(precision);
+        }
+        assert_eq!(
+            vec![
+                Precision::Shortest,
+                Precision::Exact,
+                Precision::Hex,
+                Precision::Max(4)
+            ],
+            cycle
+        );
+        assert_eq!(Precision::Shortest, Precision::Hex.next(Precision::Exact));
+    }
+
+    #[test]
+    fn test_format_float() {
+        let format = |float: Float, precision| {
+            Value::Float(float).format(&FormatOptions {
+                precision,
+                ..FormatOptions::default()
+            })
+        };
+        let f64 = Float::f64(0.1);
+        assert_eq!("0.1", format(f64, Precision::Max(4)));
+        assert_eq!("0.10000000", format(f64, Precision::Fixed(8)));
+        assert_eq!("0.1", format(f64, Precision::Shortest));
+        assert_eq!(
+            "0.1000000000000000055511151231257827021181583404541015625",
+            format(f64, Precision::Exact)
+        );
+        assert_eq!("0x1.999999999999ap-4", format(f64, Precision::Hex));
+        let f32 = Float::f32(0.1);
+        assert_eq!("0.1", format(f32, Precision::Shortest));
+        assert_eq!(
+            "0.100000001490116119384765625",
+            format(f32, Precision::Exact)
+        );
+        assert_eq!("0x1.99999ap-4", format(f32, Precision::Hex));
+        assert_eq!("NaN", format(Float::f64(f64::NAN), Precision::Exact));
+        assert_eq!(
+            "-inf",
+            format(Float::f64(f64::NEG_INFINITY), Precision::Hex)
+        );
+    }
+
+    #[test]
+    fn test_exact_decimal() {
+        assert_eq!("0", exact_decimal(0.0));
+        assert_eq!("-0", exact_decimal(-0.0));
+        assert_eq!("-1.5", exact_decimal(-1.5));
+        assert_eq!("99999999999999991611392", exact_decimal(1e23));
+        assert_eq!(
+            "340282346638528859811704183484516925440",
+            exact_decimal(f64::from(f32::MAX))
+        );
+        let min_subnormal = exact_decimal(f64::from_bits(1));
+        assert!(min_subnormal.starts_with("0.000000000000000000000000"));
+        assert!(min_subnormal.ends_with("4940656458412465441765687928682213723650598026143247644255856825006755072702087518652998363616359923797965646954457177309266567103559397963987747960107818781263007131903114045278458171678489821036887186360569987307230500063874091535649843873124733972731696151400317153853980741262385655911710266585566867681870395603106249319452715914924553293054565444011274801297099995419319894090804165633245247571478690147267801593552386115501348035264934720193790268107107491703332226844753335720832431936092382893458368060106011506169809753078342277318329247904982524730776375927247874656084778203734469699533647017972677717585125660551199131504891101451037862738167250955837389733598993664809941164205702637090279242767544565229087538682506419718265533447265625"));
+        assert_eq!(1074 + 2, min_subnormal.len());
+    }
+
+    #[test]
+    fn test_hex_float() {
+        assert_eq!("0x0p+0", hex_float(0.0));
+        assert_eq!("-0x0p+0", hex_float(-0.0));
+        assert_eq!("0x1p+0", hex_float(1.0));
+        assert_eq!("0x1.4p+2", hex_float(5.0));
+        assert_eq!("-0x1.8p-1", hex_float(-0.75));
+        assert_eq!("0x0.0000000000001p-1022", hex_float(f64::from_bits(1)));
+        assert_eq!("0x1.fffffffffffffp+1023", hex_float(f64::MAX));
+    }
+
+    #[test]
+    fn test_format_size() {
+        let format = |bytes, unit, precision| {
+            Value::Size(Size { bytes, unit }).format(&FormatOptions {
+                precision,
+                ..FormatOptions::default()
+            })
+        };
+        assert_eq!("0.9766", format(1000, 1024, Precision::Max(4)));
+        assert_eq!("0.9765625", format(1000, 1024, Precision::Exact));
+        assert_eq!("1084227.584", format(1_084_227_584, 1000, Precision::Exact));
+        assert_eq!(
+            "4591870180066957722",
+            format(0x3fb9_9999_9999_999a, 1, Precision::Exact)
+        );
+        assert_eq!(
+            "4591870180066957722",
+            format(0x3fb9_9999_9999_999a, 1, Precision::Shortest)
+        );
+        assert_eq!(
+            "4591870180066957722",
+            format(0x3fb9_9999_9999_999a, 1, Precision::Fixed(2))
+        );
+        assert_eq!("1000", format(1000, 1, Precision::Hex));
+        assert_eq!("0x1.f4p-1", format(1000, 1024, Precision::Hex));
+        assert_eq!("4.1765", format(4_484_513_562, 1 << 30, Precision::Max(4)));
+    }
+}
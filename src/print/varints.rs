@@ -0,0 +1,108 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output group for variable-length integer encodings.
+
+use super::value::format_bytes;
+use super::{Interpretation, OutputGroup, OutputLine, Value, ValueAlignment};
+use crate::parse::ParsedUserInput;
+use crate::parse::varint::VarintFormat;
+
+pub(super) fn build_varints_og(parsed: &ParsedUserInput) -> OutputGroup {
+    let bits = parsed.bits();
+
+    let mut lines = Vec::new();
+    if let Some(varint) = parsed.varint() {
+        lines.push(OutputLine::new(
+            "Decoded input".to_string(),
+            Value::Text(varint.to_string()),
+        ));
+    }
+    lines.extend(VarintFormat::ALL.iter().map(|format| {
+        let value = match format {
+            VarintFormat::Utf8 => Value::Text(format_utf8(bits)),
+            format => format
+                .encode(bits)
+                .map_or_else(|| Value::Text("(out of range)".to_string()), Value::Bytes),
+        };
+        OutputLine::new(format.to_string(), value)
+    }));
+
+    OutputGroup {
+        title: Interpretation::Varints,
+        value_alignment: ValueAlignment::Left,
+        lines,
+    }
+}
+
+/// Encodes the value as Unicode code point in UTF-8. The character itself
+/// isn't shown, as it might be a control character.
+fn format_utf8(bits: u64) -> String {
+    VarintFormat::Utf8.encode(bits).map_or_else(
+        || "(no code point)".to_string(),
+        |bytes| format!("U+{bits:04X}: {}", format_bytes(&bytes)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varints_og() {
+        let parsed = crate::parse::parse_input("624485", &Default::default()).unwrap();
+        let og = build_varints_og(&parsed);
+        assert_eq!(
+            "e5 8e 26 (3 bytes)",
+            og.lines[0].value.format(&Default::default())
+        );
+
+        let parsed = crate::parse::parse_input("0x1f600", &Default::default()).unwrap();
+        let og = build_varints_og(&parsed);
+        assert_eq!(
+            "U+1F600: f0 9f 98 80 (4 bytes)",
+            og.lines[5].value.format(&Default::default())
+        );
+
+        let parsed = crate::parse::parse_input("-1", &Default::default()).unwrap();
+        let og = build_varints_og(&parsed);
+        assert_eq!("7f (1 byte)", og.lines[1].value.format(&Default::default()));
+        assert_eq!(
+            "(out of range)",
+            og.lines[4].value.format(&Default::default())
+        );
+        assert_eq!(
+            "(no code point)",
+            og.lines[5].value.format(&Default::default())
+        );
+
+        let parsed = crate::parse::parse_input("sleb128 e5 8e 26 ff", &Default::default()).unwrap();
+        let og = build_varints_og(&parsed);
+        assert_eq!("Decoded input", og.lines[0].key);
+        assert_eq!(
+            "SLEB128, 3 of 4 bytes consumed",
+            og.lines[0].value.format(&Default::default())
+        );
+    }
+}
@@ -0,0 +1,95 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! CSV with one record per line of a group (RFC 4180).
+
+use super::Renderer;
+use crate::print::{FormatOptions, OutputGroup};
+
+/// Renders the groups as CSV with the columns `group`, `key`, and `value`.
+/// The group is its stable id, as in the config file.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CsvRenderer {
+    pub options: FormatOptions,
+}
+
+impl Renderer for CsvRenderer {
+    type Output = String;
+
+    fn render(&self, groups: &[OutputGroup]) -> String {
+        let mut csv = String::from("group,key,value\n");
+        for output_group in groups {
+            for line in output_group.lines() {
+                csv.push_str(&format!(
+                    "{},{},{}\n",
+                    field(output_group.title().id()),
+                    field(line.key().trim()),
+                    field(&line.value().format(&self.options))
+                ));
+            }
+        }
+        csv
+    }
+}
+
+/// Quotes the field if it contains a separator, a quote, or a line break.
+pub fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, get_output_group};
+
+    #[test]
+    fn test_csv_renderer() {
+        let parsed = parse_input("-1", &Default::default()).unwrap();
+        let groups = [get_output_group(
+            &parsed,
+            Interpretation::SignedIntegers,
+            &Default::default(),
+        )];
+        assert_eq!(
+            "group,key,value\n\
+             signed-integers,i8,-1\n\
+             signed-integers,i16,-1\n\
+             signed-integers,i32,-1\n\
+             signed-integers,i64,-1\n",
+            CsvRenderer::default().render(&groups)
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!("0x7f", field("0x7f"));
+        assert_eq!("\"[0x7f, 0x00]\"", field("[0x7f, 0x00]"));
+        assert_eq!("\"\"\"\\x7f\"\"\"", field("\"\\x7f\""));
+    }
+}
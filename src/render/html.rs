@@ -0,0 +1,113 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! HTML with one table per group.
+
+use super::Renderer;
+use crate::print::{FormatOptions, OutputGroup};
+
+/// Renders each group as a `<table>` with the title as caption and one row
+/// per line. The tables have the stable id of the group as class, so that
+/// they can be styled.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HtmlRenderer {
+    pub options: FormatOptions,
+}
+
+impl Renderer for HtmlRenderer {
+    type Output = String;
+
+    fn render(&self, groups: &[OutputGroup]) -> String {
+        groups
+            .iter()
+            .map(|group| self.render_group(group))
+            .collect()
+    }
+}
+
+impl HtmlRenderer {
+    fn render_group(&self, output_group: &OutputGroup) -> String {
+        let mut html = format!(
+            "<table class=\"{}\">\n  <caption>{}</caption>\n",
+            output_group.title().id(),
+            escape(&output_group.title().to_string())
+        );
+        for line in output_group.lines() {
+            html.push_str(&format!(
+                "  <tr><th>{}</th><td>{}</td></tr>\n",
+                escape(line.key().trim()),
+                escape(&line.value().format(&self.options))
+            ));
+        }
+        html.push_str("</table>\n");
+        html
+    }
+}
+
+/// Escapes the characters with a meaning in HTML text and attributes.
+pub fn escape(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, c| {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+        escaped
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, get_output_group};
+
+    #[test]
+    fn test_html_renderer() {
+        let parsed = parse_input("-1", &Default::default()).unwrap();
+        let groups = [get_output_group(
+            &parsed,
+            Interpretation::SignedIntegers,
+            &Default::default(),
+        )];
+        assert_eq!(
+            "<table class=\"signed-integers\">\n\
+             \x20 <caption>Signed Integers</caption>\n\
+             \x20 <tr><th>i8</th><td>-1</td></tr>\n\
+             \x20 <tr><th>i16</th><td>-1</td></tr>\n\
+             \x20 <tr><th>i32</th><td>-1</td></tr>\n\
+             \x20 <tr><th>i64</th><td>-1</td></tr>\n\
+             </table>\n",
+            HtmlRenderer::default().render(&groups)
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!("&lt;b&gt; &amp; &quot;&#39;", escape("<b> & \"'"));
+    }
+}
@@ -0,0 +1,171 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! JSON array with one object per group.
+
+use super::Renderer;
+use crate::print::{FloatFormat, FormatOptions, OutputGroup, Value};
+
+/// Renders the groups as a JSON array. Each group has its stable `id`
+/// (as in the config file), its `title`, and its `lines`. Each line has an
+/// `id`, a `key`, the `type` and the raw `value`, and the formatted `text`.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct JsonRenderer {
+    pub options: FormatOptions,
+}
+
+impl Renderer for JsonRenderer {
+    type Output = String;
+
+    fn render(&self, groups: &[OutputGroup]) -> String {
+        let groups = groups
+            .iter()
+            .map(|group| self.render_group(group))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!("[\n{groups}\n]\n")
+    }
+}
+
+impl JsonRenderer {
+    fn render_group(&self, output_group: &OutputGroup) -> String {
+        let lines = output_group
+            .lines()
+            .iter()
+            .map(|line| {
+                format!(
+                    "      {{ \"id\": {}, \"key\": {}, \"type\": {}, \"value\": {}, \"text\": {} }}",
+                    string(line.id()),
+                    string(line.key().trim()),
+                    string(type_name(line.value())),
+                    value(line.value()),
+                    string(&line.value().format(&self.options))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "  {{\n    \"id\": {},\n    \"title\": {},\n    \"lines\": [\n{lines}\n    ]\n  }}",
+            string(output_group.title().id()),
+            string(&output_group.title().to_string()),
+        )
+    }
+}
+
+const fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Integer(_) => "integer",
+        Value::Size(size) if size.is_in_bytes() => "integer",
+        Value::Float(_) | Value::Size(_) => "float",
+        Value::Decimal(_) => "decimal",
+        Value::Bytes(_) => "bytes",
+        Value::Date(_) => "date",
+        Value::Text(_) => "text",
+    }
+}
+
+/// The raw value: numbers for integers, floats, sizes, and decimals, an array of
+/// numbers for bytes, and seconds and nanoseconds since the Unix epoch for
+/// dates. Floats that JSON can't represent, such as NaN, become strings.
+fn value(value: &Value) -> String {
+    match value {
+        Value::Integer(integer) => integer.value.to_string(),
+        Value::Float(float) if float.value.is_finite() => match float.format {
+            // shortest representation that reads back as the same f32
+            FloatFormat::F32 => format!("{:?}", float.value as f32),
+            FloatFormat::F64 => format!("{:?}", float.value),
+        },
+        Value::Float(float) => string(&float.value.to_string()),
+        Value::Size(size) if size.is_in_bytes() => size.bytes.to_string(),
+        Value::Size(size) => format!("{:?}", size.value()),
+        Value::Decimal(decimal) => decimal.clone(),
+        Value::Bytes(bytes) => format!(
+            "[{}]",
+            bytes
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Date(date) => format!(
+            "{{ \"seconds\": {}, \"nanos\": {} }}",
+            date.seconds, date.nanos
+        ),
+        Value::Text(text) => string(text),
+    }
+}
+
+/// Formats a JSON string literal with all required escapes.
+fn string(value: &str) -> String {
+    let mut string = String::with_capacity(value.len() + 2);
+    string.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            c if c.is_control() => string.push_str(&format!("\\u{:04x}", c as u32)),
+            c => string.push(c),
+        }
+    }
+    string.push('"');
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Float, Interpretation, get_output_group};
+
+    #[test]
+    fn test_json_renderer() {
+        let parsed = parse_input("-1", &Default::default()).unwrap();
+        let groups = [get_output_group(
+            &parsed,
+            Interpretation::SignedIntegers,
+            &Default::default(),
+        )];
+        assert_eq!(
+            "[\n  {\n    \"id\": \"signed-integers\",\n    \"title\": \"Signed Integers\",\n    \"lines\": [\n      { \"id\": \"i8\", \"key\": \"i8\", \"type\": \"integer\", \"value\": -1, \"text\": \"-1\" },\n      { \"id\": \"i16\", \"key\": \"i16\", \"type\": \"integer\", \"value\": -1, \"text\": \"-1\" },\n      { \"id\": \"i32\", \"key\": \"i32\", \"type\": \"integer\", \"value\": -1, \"text\": \"-1\" },\n      { \"id\": \"i64\", \"key\": \"i64\", \"type\": \"integer\", \"value\": -1, \"text\": \"-1\" }\n    ]\n  }\n]\n",
+            JsonRenderer::default().render(&groups)
+        );
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!("0.1", value(&Value::Float(Float::f32(0.1))));
+        assert_eq!("\"NaN\"", value(&Value::Float(Float::f64(f64::NAN))));
+        assert_eq!("[229, 142]", value(&Value::Bytes(vec![0xe5, 0x8e])));
+        assert_eq!("-1.25", value(&Value::Decimal("-1.25".to_string())));
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(r#""a\"b\\c\n\u0007""#, string("a\"b\\c\n\x07"));
+        assert_eq!("\"µs\"", string("µs"));
+    }
+}
@@ -0,0 +1,118 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Markdown with one table per group.
+
+use super::Renderer;
+use crate::print::{FormatOptions, OutputGroup};
+
+/// Renders each group as a heading with its title, followed by a table
+/// with the columns `Key` and `Value`.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MarkdownRenderer {
+    pub options: FormatOptions,
+}
+
+impl Renderer for MarkdownRenderer {
+    type Output = String;
+
+    fn render(&self, groups: &[OutputGroup]) -> String {
+        groups
+            .iter()
+            .map(|group| self.render_group(group))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl MarkdownRenderer {
+    fn render_group(&self, output_group: &OutputGroup) -> String {
+        output_group.lines().iter().fold(
+            format!(
+                "### {}\n\n| Key | Value |\n| --- | --- |\n",
+                escape(&output_group.title().to_string())
+            ),
+            |mut markdown, line| {
+                markdown.push_str(&format!(
+                    "| {} | {} |\n",
+                    escape(line.key().trim()),
+                    escape(&line.value().format(&self.options))
+                ));
+                markdown
+            },
+        )
+    }
+}
+
+/// Escapes characters with a meaning in Markdown, including the `|` of
+/// tables.
+pub fn escape(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, c| {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '|' | '<' | '>' | '[' | ']' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        escaped
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, get_output_group};
+
+    #[test]
+    fn test_markdown_renderer() {
+        let parsed = parse_input("-1", &Default::default()).unwrap();
+        let groups = [get_output_group(
+            &parsed,
+            Interpretation::SignedIntegers,
+            &Default::default(),
+        )];
+        assert_eq!(
+            "### Signed Integers\n\
+             \n\
+             | Key | Value |\n\
+             | --- | --- |\n\
+             | i8 | -1 |\n\
+             | i16 | -1 |\n\
+             | i32 | -1 |\n\
+             | i64 | -1 |\n",
+            MarkdownRenderer::default().render(&groups)
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(r"O\_RDWR\|O\_CREAT", escape("O_RDWR|O_CREAT"));
+        assert_eq!(
+            r"Size in \*ebi/\*ibi Bytes",
+            escape("Size in *ebi/*ibi Bytes")
+        );
+    }
+}
@@ -0,0 +1,149 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Renderers that turn [`OutputGroup`]s into an output format. All formats
+//! render the same groups, so a new interpretation shows up in each of them.
+
+mod csv;
+mod html;
+mod json;
+mod markdown;
+mod plain;
+mod report;
+
+pub use csv::CsvRenderer;
+use derive_more::Display;
+pub use html::HtmlRenderer;
+pub use json::JsonRenderer;
+pub use markdown::MarkdownRenderer;
+pub use plain::PlainRenderer;
+pub use report::{REPORT_FORMATS, ReportRow, render_report};
+
+use crate::print::{FormatOptions, OutputGroup};
+
+/// Turns output groups into the output of one format.
+pub trait Renderer {
+    /// The rendered groups, such as a string or widgets.
+    type Output;
+
+    /// Renders the groups in the given order.
+    fn render(&self, groups: &[OutputGroup]) -> Self::Output;
+}
+
+/// Output formats, passed via `--format`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Display)]
+pub enum Format {
+    /// The interactive view.
+    #[default]
+    #[display("tui")]
+    Tui,
+    /// Aligned `key: value` lines.
+    #[display("plain")]
+    Plain,
+    #[display("json")]
+    Json,
+    #[display("csv")]
+    Csv,
+    #[display("markdown")]
+    Markdown,
+    #[display("html")]
+    Html,
+}
+
+impl Format {
+    /// All formats.
+    pub const ALL: [Self; 6] = [
+        Self::Tui,
+        Self::Plain,
+        Self::Json,
+        Self::Csv,
+        Self::Markdown,
+        Self::Html,
+    ];
+
+    /// Parses the [`Format`] from a normalized string, such as `json`.
+    /// `md` is accepted for Markdown.
+    pub fn from_input(format: &str) -> Option<Self> {
+        match format {
+            "md" => Some(Self::Markdown),
+            _ => Self::ALL
+                .into_iter()
+                .find(|candidate| candidate.to_string() == format),
+        }
+    }
+
+    /// Renders the groups as text with values formatted according to
+    /// `options`. Returns `None` for [`Format::Tui`], as the interactive
+    /// view isn't text.
+    pub fn render_text(self, groups: &[OutputGroup], options: FormatOptions) -> Option<String> {
+        match self {
+            Self::Tui => None,
+            Self::Plain => Some(PlainRenderer { options }.render(groups)),
+            Self::Json => Some(JsonRenderer { options }.render(groups)),
+            Self::Csv => Some(CsvRenderer { options }.render(groups)),
+            Self::Markdown => Some(MarkdownRenderer { options }.render(groups)),
+            Self::Html => Some(HtmlRenderer { options }.render(groups)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, get_output_group};
+
+    #[test]
+    fn test_format_from_input() {
+        for format in Format::ALL {
+            assert_eq!(Some(format), Format::from_input(&format.to_string()));
+        }
+        assert_eq!(Some(Format::Markdown), Format::from_input("md"));
+        assert_eq!(None, Format::from_input("xml"));
+    }
+
+    #[test]
+    fn test_all_groups_in_all_formats() {
+        let parsed = parse_input("0x40a00000", &Default::default()).unwrap();
+        let groups = Interpretation::ALL
+            .map(|interpretation| get_output_group(&parsed, interpretation, &Default::default()));
+        for format in Format::ALL {
+            let Some(text) = format.render_text(&groups, FormatOptions::default()) else {
+                continue;
+            };
+            for group in &groups {
+                let title = match format {
+                    Format::Markdown => markdown::escape(&group.title().to_string()),
+                    Format::Html => html::escape(&group.title().to_string()),
+                    _ => group.title().to_string(),
+                };
+                let id = group.title().id();
+                assert!(
+                    text.contains(&title) || text.contains(id),
+                    "{format} misses {title}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Plain text with aligned values, as shown in the interactive view.
+
+use super::Renderer;
+use crate::print::{FormatOptions, OutputGroup};
+
+/// Renders each group as its title, followed by one aligned `key: value`
+/// line for each line of the group. Groups are separated by an empty line.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PlainRenderer {
+    pub options: FormatOptions,
+}
+
+impl Renderer for PlainRenderer {
+    type Output = String;
+
+    fn render(&self, groups: &[OutputGroup]) -> String {
+        groups
+            .iter()
+            .map(|group| self.render_group(group))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl PlainRenderer {
+    fn render_group(&self, output_group: &OutputGroup) -> String {
+        output_group.iter(&self.options).fold(
+            format!("{}\n", output_group.title()),
+            |mut string, (key, value)| {
+                // left aligned values are padded to the longest value
+                string.push_str(format!("{key}: {value}").trim_end());
+                string.push('\n');
+                string
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, get_output_group};
+
+    #[test]
+    fn test_plain_renderer() {
+        let parsed = parse_input("0x7f", &Default::default()).unwrap();
+        let groups = [
+            get_output_group(&parsed, Interpretation::NumeralSystems, &Default::default()),
+            get_output_group(&parsed, Interpretation::SignedIntegers, &Default::default()),
+        ];
+        assert_eq!(
+            "Numeral Systems\n\
+             Dec:          127\n\
+             Bin (0b): 1111111\n\
+             Oct (0o):     177\n\
+             Hex (0x):      7f\n\
+             \n\
+             Signed Integers\n\
+             \x20i8: 127\n\
+             i16: 127\n\
+             i32: 127\n\
+             i64: 127\n",
+            PlainRenderer::default().render(&groups)
+        );
+    }
+}
@@ -0,0 +1,304 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Reports over many inputs, with one row per input. They are built from
+//! the same output groups as all other formats.
+
+use super::{Format, csv, html, markdown};
+use crate::print::{FormatOptions, OutputGroup};
+
+/// The output groups of one input. All rows of a report have the same
+/// groups in the same order.
+#[derive(Debug)]
+pub struct ReportRow {
+    /// The input, as typed by the user.
+    pub input: String,
+    pub groups: Vec<OutputGroup>,
+}
+
+/// Formats supported by [`render_report`].
+pub const REPORT_FORMATS: [Format; 3] = [Format::Markdown, Format::Csv, Format::Html];
+
+/// Renders the rows as Markdown (one table per group), CSV (one column per
+/// line of all groups), or HTML (one table per group, with its own style).
+/// Returns `None` for other formats.
+pub fn render_report(
+    format: Format,
+    rows: &[ReportRow],
+    options: &FormatOptions,
+) -> Option<String> {
+    let report = Report { rows, options };
+    match format {
+        Format::Markdown => Some(report.markdown()),
+        Format::Csv => Some(report.csv()),
+        Format::Html => Some(report.html()),
+        Format::Tui | Format::Plain | Format::Json => None,
+    }
+}
+
+/// A line of a group, as column of a report.
+struct Column {
+    id: String,
+    key: String,
+}
+
+struct Report<'a> {
+    rows: &'a [ReportRow],
+    options: &'a FormatOptions,
+}
+
+impl Report<'_> {
+    /// The number of groups per row.
+    fn group_count(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.groups.len())
+    }
+
+    /// The group with the given index of the first row, for its title.
+    fn group(&self, group: usize) -> Option<&OutputGroup> {
+        self.rows.first().map(|row| &row.groups[group])
+    }
+
+    /// The lines of the group in all rows. Some lines only exist for some
+    /// inputs, such as IPv6 addresses; they are added in the order in which
+    /// they appear. Lines of different rows with the same id share a column,
+    /// so the ids must be unique within a group.
+    fn columns(&self, group: usize) -> Vec<Column> {
+        let mut columns: Vec<Column> = Vec::new();
+        for row in self.rows {
+            let output_group = &row.groups[group];
+            debug_assert!(
+                output_group.duplicate_line_id().is_none(),
+                "duplicate line id {:?} in {}",
+                output_group.duplicate_line_id(),
+                output_group.title()
+            );
+            for line in output_group.lines() {
+                if !columns.iter().any(|column| column.id == line.id()) {
+                    columns.push(Column {
+                        id: line.id().to_owned(),
+                        key: line.key().trim().to_owned(),
+                    });
+                }
+            }
+        }
+        columns
+    }
+
+    /// The formatted value of the line in the group of the row. Empty, if
+    /// the line doesn't exist for the input of the row.
+    fn cell(&self, row: &ReportRow, group: usize, column: &Column) -> String {
+        row.groups[group]
+            .lines()
+            .iter()
+            .find(|line| line.id() == column.id)
+            .map(|line| line.value().format(self.options))
+            .unwrap_or_default()
+    }
+
+    fn markdown(&self) -> String {
+        let tables = (0..self.group_count()).map(|group| {
+            let columns = self.columns(group);
+            let title = self.group(group).unwrap().title().to_string();
+            let mut table = format!("### {}\n\n| Input |", markdown::escape(&title));
+            for column in &columns {
+                table.push_str(&format!(" {} |", markdown::escape(&column.key)));
+            }
+            table.push_str(&format!("\n|{}\n", " --- |".repeat(columns.len() + 1)));
+            for row in self.rows {
+                table.push_str(&format!("| {} |", markdown::escape(&row.input)));
+                for column in &columns {
+                    let cell = self.cell(row, group, column);
+                    table.push_str(&format!(" {} |", markdown::escape(&cell)));
+                }
+                table.push('\n');
+            }
+            table
+        });
+        tables.collect::<Vec<_>>().join("\n")
+    }
+
+    fn csv(&self) -> String {
+        let columns = (0..self.group_count())
+            .map(|group| (group, self.columns(group)))
+            .collect::<Vec<_>>();
+        let mut csv = String::from("input");
+        for (group, group_columns) in &columns {
+            let id = self.group(*group).unwrap().title().id();
+            for column in group_columns {
+                csv.push_str(&format!(",{}", csv::field(&format!("{id}.{}", column.id))));
+            }
+        }
+        csv.push('\n');
+        for row in self.rows {
+            csv.push_str(&csv::field(&row.input));
+            for (group, group_columns) in &columns {
+                for column in group_columns {
+                    csv.push(',');
+                    csv.push_str(&csv::field(&self.cell(row, *group, column)));
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    fn html(&self) -> String {
+        let mut html = String::from(
+            "<div class=\"wambo-report\">\n\
+             <style>\n\
+             .wambo-report table { border-collapse: collapse; margin-bottom: 1em; }\n\
+             .wambo-report caption { font-weight: bold; text-align: left; }\n\
+             .wambo-report th, .wambo-report td { border: 1px solid #999; padding: 0.2em 0.5em; }\n\
+             .wambo-report td { font-family: monospace; text-align: right; }\n\
+             </style>\n",
+        );
+        for group in 0..self.group_count() {
+            let columns = self.columns(group);
+            let output_group = self.group(group).unwrap();
+            html.push_str(&format!(
+                "<table class=\"{}\">\n  <caption>{}</caption>\n  <tr><th>Input</th>",
+                output_group.title().id(),
+                html::escape(&output_group.title().to_string())
+            ));
+            for column in &columns {
+                html.push_str(&format!("<th>{}</th>", html::escape(&column.key)));
+            }
+            html.push_str("</tr>\n");
+            for row in self.rows {
+                html.push_str(&format!("  <tr><th>{}</th>", html::escape(&row.input)));
+                for column in &columns {
+                    let cell = self.cell(row, group, column);
+                    html.push_str(&format!("<td>{}</td>", html::escape(&cell)));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</table>\n");
+        }
+        html.push_str("</div>\n");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+    use crate::print::{Interpretation, OutputLine, get_output_group};
+
+    fn rows(inputs: &[&str], interpretations: &[Interpretation]) -> Vec<ReportRow> {
+        inputs
+            .iter()
+            .map(|input| {
+                let parsed = parse_input(input, &Default::default()).unwrap();
+                ReportRow {
+                    input: input.to_string(),
+                    groups: interpretations
+                        .iter()
+                        .map(|interpretation| {
+                            get_output_group(&parsed, *interpretation, &Default::default())
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate line id")]
+    fn test_duplicate_line_id() {
+        let rows = vec![ReportRow {
+            input: "-2".to_string(),
+            groups: vec![OutputGroup::from_lines(
+                Interpretation::OsErrors,
+                vec![
+                    OutputLine::with_id("errno", "errno".to_string(), "-".to_string()),
+                    OutputLine::with_id("errno", "-errno".to_string(), "ENOENT".to_string()),
+                ],
+            )],
+        }];
+        render_report(Format::Markdown, &rows, &Default::default());
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let rows = rows(&["42", "0xff"], &[Interpretation::NumeralSystems]);
+        assert_eq!(
+            "### Numeral Systems\n\
+             \n\
+             | Input | Dec | Bin (0b) | Oct (0o) | Hex (0x) |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | 42 | 42 | 101010 | 52 | 2a |\n\
+             | 0xff | 255 | 11111111 | 377 | ff |\n",
+            render_report(Format::Markdown, &rows, &Default::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_csv_report() {
+        let rows = rows(
+            &["42", "fe80::1"],
+            &[Interpretation::SignedIntegers, Interpretation::Network],
+        );
+        let csv = render_report(Format::Csv, &rows, &Default::default()).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            "input,signed-integers.i8,signed-integers.i16,signed-integers.i32,\
+             signed-integers.i64,network.ipv4-network,network.ipv4-le-host,\
+             network.mac-eui-48,network.mac-flags,network.port-network,\
+             network.port-le-host,network.ipv6",
+            lines.next().unwrap()
+        );
+        // the IPv6 column is empty for inputs that aren't IPv6 addresses
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("42,42,42,42,42,0.0.0.42,")
+        );
+        assert!(lines.next().unwrap().ends_with(",fe80::1"));
+        assert_eq!(None, lines.next());
+    }
+
+    #[test]
+    fn test_html_report() {
+        let rows = rows(&["-1"], &[Interpretation::UnsignedIntegers]);
+        let html = render_report(Format::Html, &rows, &Default::default()).unwrap();
+        assert!(html.starts_with("<div class=\"wambo-report\">\n<style>"));
+        assert!(html.contains(
+            "  <tr><th>Input</th><th>u8</th><th>u16</th><th>u32</th><th>u64</th></tr>\n\
+             \x20 <tr><th>-1</th><td>255</td><td>65535</td><td>4294967295</td>\
+             <td>18446744073709551615</td></tr>\n"
+        ));
+        assert!(html.ends_with("</table>\n</div>\n"));
+    }
+
+    #[test]
+    fn test_unsupported_formats() {
+        let rows = rows(&["42"], &[Interpretation::NumeralSystems]);
+        for format in Format::ALL {
+            let report = render_report(format, &rows, &Default::default());
+            assert_eq!(REPORT_FORMATS.contains(&format), report.is_some());
+        }
+    }
+}
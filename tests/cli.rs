@@ -0,0 +1,253 @@
+/*
+MIT License
+
+Copyright (c) 2024 Philipp Schuster
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Integration tests that run the `wambo` binary in the non-interactive
+//! output modes and compare its exit status, stdout, and stderr with the
+//! golden files in `tests/golden/`.
+//!
+//! On a mismatch, the actual result is written next to the golden file as
+//! `<name>.txt.new`. Review the difference and either fix the code or
+//! accept all new results with `UPDATE_SNAPSHOTS=1 cargo test`.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Runs the binary with the args and, if present, the stdin. The config
+/// and history of the user are not used, so that the results are the same
+/// on every machine.
+fn run(args: &[&str], stdin: Option<&str>) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wambo"))
+        .args(args)
+        .env(
+            "XDG_CONFIG_HOME",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("no-config"),
+        )
+        .env("WAMBO_NO_HISTORY", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // dropping stdin closes it, also if there is nothing to write
+    let mut child_stdin = child.stdin.take().unwrap();
+    child_stdin
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(child_stdin);
+    let output = child.wait_with_output().unwrap();
+
+    let mut result = format!("$ wambo {}\n", args.join(" "));
+    if let Some(stdin) = stdin {
+        result += &format!("--- stdin\n{stdin}");
+    }
+    result += &format!("--- exit status: {:?}\n", output.status.code());
+    result += &format!("--- stdout\n{}", String::from_utf8_lossy(&output.stdout));
+    result += &format!("--- stderr\n{}", String::from_utf8_lossy(&output.stderr));
+    result
+}
+
+/// Compares the result with the golden file `name`.
+fn assert_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.txt"));
+    let new_path = path.with_extension("txt.new");
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        let _ = fs::remove_file(&new_path);
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    if expected != actual {
+        fs::write(&new_path, actual).unwrap();
+        panic!(
+            "the result differs from the golden file {}\n\
+             review it with `diff {} {}`, and accept all new results with \
+             `UPDATE_SNAPSHOTS=1 cargo test`",
+            path.display(),
+            path.display(),
+            new_path.display(),
+        );
+    }
+    let _ = fs::remove_file(&new_path);
+}
+
+/// Defines a test that runs the binary with the args (and the stdin) and
+/// compares the result with the golden file of the same name.
+macro_rules! golden_test {
+    ($name:ident, [$($arg:expr),* $(,)?]) => {
+        golden_test!($name, [$($arg),*], None);
+    };
+    ($name:ident, [$($arg:expr),* $(,)?], $stdin:expr) => {
+        #[test]
+        fn $name() {
+            assert_golden(stringify!($name), &run(&[$($arg),*], $stdin));
+        }
+    };
+}
+
+// numeral systems
+golden_test!(decimal, ["42", "--format", "plain"]);
+golden_test!(binary, ["0b101010", "--format", "plain"]);
+golden_test!(octal, ["0o52", "--format", "plain"]);
+golden_test!(hex, ["0x2A", "--format", "plain"]);
+golden_test!(underscores, ["0xdead_beef", "--format", "plain"]);
+
+// encoded inputs; the trailing byte isn't consumed
+golden_test!(varint, ["sleb128 e5 8e 26 ff", "--format", "plain"]);
+golden_test!(varint_utf8, ["utf8", "e2", "82", "ac", "--format", "plain"]);
+golden_test!(invalid_utf8, ["utf8 c0 80", "--format", "plain"]);
+
+// units, one row per input
+golden_test!(
+    units,
+    ["-", "--export", "csv"],
+    Some(
+        "1k\n1kb\n1ki\n1kib\n1m\n1mb\n1mi\n1mib\n1g\n1gb\n1gi\n1gib\n1t\n1tb\n1ti\n1tib\n1teb\n1tebi\n"
+    )
+);
+
+// signs
+golden_test!(negative, ["-1", "--format", "plain"]);
+golden_test!(negative_hex_unit, ["-0xfmib", "--format", "plain"]);
+golden_test!(i8_min, ["-128", "--format", "plain"]);
+golden_test!(negative_size, ["-16mib", "--format", "plain"]);
+
+// the limits of 64 bits
+golden_test!(u64_max, ["0xffffffffffffffff", "--format", "plain"]);
+golden_test!(
+    overflow_decimal,
+    ["18446744073709551616", "--format", "plain"]
+);
+golden_test!(
+    overflow_hex,
+    ["0x1_0000_0000_0000_0000", "--format", "plain"]
+);
+golden_test!(
+    overflow_binary,
+    [
+        "0b10000000000000000000000000000000000000000000000000000000000000000",
+        "--format",
+        "plain"
+    ]
+);
+golden_test!(overflow_unit, ["16777216tib", "--format", "plain"]);
+golden_test!(
+    overflow_byte_sequence,
+    [
+        "00 11 22 33 44 55 66 77 88 99 aa bb cc dd ee ff 00",
+        "--format",
+        "plain"
+    ]
+);
+
+// errors of the input
+golden_test!(invalid_format, ["0x-1", "--format", "plain"]);
+golden_test!(invalid_digits, ["0b102", "--format", "plain"]);
+golden_test!(invalid_unit, ["1eib", "--format", "plain"]);
+golden_test!(invalid_q_format, ["0.5q99", "--format", "plain"]);
+golden_test!(q_format_out_of_range, ["2.5q15", "--format", "plain"]);
+golden_test!(empty_byte_sequence, ["[]", "--format", "plain"]);
+golden_test!(missing_reference, ["$_ + 1", "--format", "plain"]);
+golden_test!(
+    invalid_export_line,
+    ["-", "--export", "md"],
+    Some("42\nnope\n")
+);
+
+// errors of the arguments
+golden_test!(missing_input, ["--format", "plain"]);
+golden_test!(invalid_format_option, ["42", "--format", "yaml"]);
+golden_test!(invalid_endian, ["de ad", "--endian", "middle"]);
+golden_test!(end_of_options, ["--format", "plain", "--", "--literals"]);
+
+// precision of floats and sizes
+golden_test!(
+    precision_digits,
+    [
+        "0x3fb999999999999a",
+        "--format",
+        "plain",
+        "--precision",
+        "8"
+    ]
+);
+golden_test!(
+    precision_fixed,
+    [
+        "0x3fb999999999999a",
+        "--format",
+        "plain",
+        "--precision",
+        "fixed:3"
+    ]
+);
+golden_test!(
+    precision_shortest,
+    [
+        "0x3fb999999999999a",
+        "--format",
+        "plain",
+        "--precision",
+        "shortest"
+    ]
+);
+golden_test!(
+    precision_exact,
+    [
+        "0x3fb999999999999a",
+        "--format",
+        "plain",
+        "--precision",
+        "exact"
+    ]
+);
+golden_test!(
+    precision_hex,
+    [
+        "0x3fb999999999999a",
+        "--format",
+        "plain",
+        "--precision",
+        "hex"
+    ]
+);
+golden_test!(invalid_precision, ["42", "--precision", "fixed:21"]);
+
+// output formats
+golden_test!(format_json, ["0x40a00000", "--format", "json"]);
+golden_test!(format_csv, ["0x40a00000", "--format", "csv"]);
+golden_test!(format_markdown, ["0x40a00000", "--format", "md"]);
+golden_test!(format_html, ["0x40a00000", "--format", "html"]);
+golden_test!(literals, ["de ad be ef", "--endian", "big", "--literals"]);
+golden_test!(
+    export_markdown,
+    ["-", "--export", "md"],
+    Some("42\n-1\n1mib - 0x10\n")
+);
+golden_test!(export_html, ["0x40a00000", "--export", "html"]);
+golden_test!(export_negative, ["-2", "--export", "md"]);
+golden_test!(history_disabled, ["--history"]);